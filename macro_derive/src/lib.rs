@@ -2,7 +2,7 @@ use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use quote::quote;
 use syn::{parse_macro_input, Data, DataEnum, DataStruct, DeriveInput, Expr, Fields, Lit};
-use utils::{get_attr, get_attr_opt};
+use utils::{get_attr, get_attr_opt, once_cell_type};
 
 mod utils;
 
@@ -21,7 +21,7 @@ pub fn once_cell_derive(input: TokenStream) -> TokenStream {
     let field_name = fields.iter().map(|f| &f.ident);
     let field_fn_name = fields.iter().map(|field| {
         if let Some(ident) = &field.ident {
-            let name = format!("get_{}", ident.to_string());
+            let name = format!("get_{}", ident);
             Some(proc_macro2::Ident::new(&name, ident.span()))
         } else {
             None
@@ -30,7 +30,7 @@ pub fn once_cell_derive(input: TokenStream) -> TokenStream {
 
     let field_mut_fn_name = fields.iter().map(|field| {
         if let Some(ident) = &field.ident {
-            let name = format!("get_mut_{}", ident.to_string());
+            let name = format!("get_mut_{}", ident);
             Some(proc_macro2::Ident::new(&name, ident.span()))
         } else {
             None
@@ -41,21 +41,54 @@ pub fn once_cell_derive(input: TokenStream) -> TokenStream {
         .iter()
         .map(|f| get_attr::<Lit>(&f.attrs, "config_path"));
 
+    let field_type = fields.iter().map(|f| once_cell_type(&f.ty));
+    let field_mut_type = field_type.clone();
+
+    // Only the tables of plain strings can be changed by name, like from a theme in a config file
+    let string_tables: Vec<_> = fields
+        .iter()
+        .filter(|f| {
+            let ty = once_cell_type(&f.ty);
+            quote!(#ty).to_string() == quote!(HashMap<String, String>).to_string()
+        })
+        .filter_map(|f| f.ident.as_ref())
+        .collect();
+    let table_name = string_tables.iter().map(|ident| ident.to_string());
+    let table_mut_fn_name = string_tables
+        .iter()
+        .map(|ident| proc_macro2::Ident::new(&format!("get_mut_{}", ident), ident.span()));
+    let table_field_name = string_tables.iter();
+
     let struct_name = &input.ident;
 
     TokenStream::from(quote! {
         impl #struct_name {
             #(
-                pub fn #field_fn_name(&self) -> &::std::collections::HashMap<&'static str, &'static str> {
+                pub fn #field_fn_name(&self) -> &#field_type {
                     self.#field_name.get_or_init(|| ron::from_str(include_str!(#config_path)).unwrap())
                 }
 
-                pub fn #field_mut_fn_name(&mut self) -> &mut ::std::collections::HashMap<&'static str, &'static str> {
+                pub fn #field_mut_fn_name(&mut self) -> &mut #field_mut_type {
                     let _ = self.#field_fn_name();
                     self.#field_name.get_mut().unwrap()
                 }
             )*
         }
+
+        impl crate::config::ThemeCategory for #struct_name {
+            fn table_mut(&mut self, name: &str) -> Option<&mut ::std::collections::HashMap<String, String>> {
+                match name {
+                    #(
+                        #table_name => Some(self.#table_mut_fn_name()),
+                    )*
+                    _ => None,
+                }
+            }
+
+            fn table_names(&self) -> &'static [&'static str] {
+                &[#(stringify!(#table_field_name)),*]
+            }
+        }
     })
 }
 
@@ -88,7 +121,7 @@ pub fn derive_enum_parser_fn(input: TokenStream) -> TokenStream {
         fn #parser_name(input: &str) -> nom::IResult<&str, #out_type> {
             nom::branch::alt((
                 #(
-                    nom::combinator::map(nom::bytes::complete::tag(#variant_tag), |_| #out_type::#variant_name),
+                    nom::combinator::map(nom::combinator::all_consuming(nom::bytes::complete::tag(#variant_tag)), |_| #out_type::#variant_name),
                 )*
             ))(input)
         }
@@ -109,14 +142,11 @@ pub fn derive_configurable_parser_fn(input: TokenStream) -> TokenStream {
                 nom::sequence::preceded(
                     nom::bytes::complete::tag("-"),
                     nom::combinator::map(
-                        nom::branch::alt((
-                            nom::sequence::delimited(nom::bytes::complete::tag("["), nom::bytes::complete::is_not("]"), nom::bytes::complete::tag("]")),
-                            nom::combinator::map_opt(nom::bytes::complete::is_not(" "), |v| config.#config().get(v).copied()),
-                        )),
+                        crate::class::utils::arbitrary_hashmap_value(config.#config()),
                         #out_type,
                     )
                 ),
-                nom::combinator::map_opt(nom::bytes::complete::tag(""), |_| config.#config().get("").map(|v| #out_type(v)))
+                nom::combinator::map_opt(nom::combinator::eof, |_| config.#config().get("").map(|v| #out_type(v.as_str())))
             ))(input)
         }
     })
@@ -160,7 +190,7 @@ pub fn derive_configurable_parser_fn(input: TokenStream) -> TokenStream {
 //                     ),
 //                     nom::combinator::map_opt(
 //                         nom::bytes::complete::tag(#keyword),
-//                         |_| config.#config().get("").map(|v| #out_type(v))
+//                         |_| config.#config().get("").map(|v| #out_type(v.as_str()))
 //                     ),
 //                 ))(input)
 //             }
@@ -209,10 +239,7 @@ pub fn derive_configurable_enum_parser_fn(input: TokenStream) -> TokenStream {
                     nom::combinator::map(
                         nom::sequence::preceded(
                             nom::sequence::terminated(nom::bytes::complete::tag(#variant_tag), nom::bytes::complete::tag("-")),
-                            nom::branch::alt((
-                                nom::sequence::delimited(nom::bytes::complete::tag("["), nom::bytes::complete::is_not("]"), nom::bytes::complete::tag("]")),
-                                nom::combinator::map_opt(nom::bytes::complete::is_not(" "), |v| config.#config().get(v).copied())
-                            )),
+                            crate::class::utils::arbitrary_hashmap_value(config.#config()),
                         ),
                         |s| #out_type::#variant_name(s)
                     ),
@@ -233,8 +260,8 @@ pub fn derive_into_declaration(input: TokenStream) -> TokenStream {
 
             TokenStream::from(quote! {
                 impl<'a> crate::class::IntoDeclaration for #out_type<'a> {
-                    fn to_decl(self) -> crate::class::Decl {
-                        crate::class::Decl::String(format!("{}: {}", #decl_name, self.0))
+                    fn to_decl(self) -> Vec<crate::css::Declaration> {
+                        vec![crate::css::Declaration::new(#decl_name, self.0)]
                     }
                 }
             })
@@ -252,14 +279,14 @@ pub fn derive_into_declaration(input: TokenStream) -> TokenStream {
 
             TokenStream::from(quote! {
                 impl crate::class::IntoDeclaration for #out_type {
-                    fn to_decl(self) -> crate::class::Decl {
+                    fn to_decl(self) -> Vec<crate::css::Declaration> {
                         let val = match self {
                             #(
                                 Self::#variant_name => #variant_decl,
                             )*
                         };
 
-                        crate::class::Decl::String(format!("{}: {}", #decl_name, val))
+                        vec![crate::css::Declaration::new(#decl_name, val)]
                     }
                 }
             })
@@ -1,4 +1,4 @@
-use syn::{parse::Parse, Attribute};
+use syn::{parse::Parse, Attribute, GenericArgument, PathArguments, Type};
 
 pub fn get_attr<T>(attrs: &Vec<Attribute>, name: &str) -> T
 where
@@ -6,9 +6,9 @@ where
 {
     for attr in attrs {
         if attr.path().is_ident(name) {
-            let name: T = attr.parse_args().expect(&format!(
-                "failed to parse attribute {name} to the required type"
-            ));
+            let name: T = attr.parse_args().unwrap_or_else(|_| {
+                panic!("failed to parse attribute {name} to the required type")
+            });
             return name;
         }
     }
@@ -21,12 +21,27 @@ where
 {
     for attr in attrs {
         if attr.path().is_ident(name) {
-            let name: T = attr.parse_args().expect(&format!(
-                "failed to parse attribute {name} to the required type"
-            ));
+            let name: T = attr.parse_args().unwrap_or_else(|_| {
+                panic!("failed to parse attribute {name} to the required type")
+            });
             return Some(name);
         }
     }
 
     None
 }
+
+/// The `T` of a field with the type `OnceCell<T>`
+pub fn once_cell_type(ty: &Type) -> &Type {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                    return inner;
+                }
+            }
+        }
+    }
+
+    panic!("expected a field with the type OnceCell<T>")
+}
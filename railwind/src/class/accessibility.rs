@@ -2,7 +2,8 @@ use macro_derive::EnumParser;
 use nom::combinator::map;
 use nom::IResult;
 
-use crate::class::{Decl, IntoDeclaration};
+use crate::class::IntoDeclaration;
+use crate::css::Declaration;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Accessibility {
@@ -14,7 +15,7 @@ pub fn accessibility(input: &str) -> IResult<&str, Accessibility> {
 }
 
 impl IntoDeclaration for Accessibility {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Accessibility::ScreenReaders(a) => a.to_decl(),
         }
@@ -31,29 +32,29 @@ pub enum ScreenReaders {
 }
 
 impl IntoDeclaration for ScreenReaders {
-    fn to_decl(self) -> super::Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::SrOnly => Decl::LitVec(vec![
-                "position: absolute",
-                "width: 1px",
-                "height: 1px",
-                "padding: 0",
-                "margin: -1px",
-                "overflow: hidden",
-                "clip: rect(0, 0, 0, 0)",
-                "white-space: nowrap",
-                "border-width: 0",
-            ]),
-            Self::NotSrOnly => Decl::LitVec(vec![
-                "position: static",
-                "width: auto",
-                "height: auto",
-                "padding: 0",
-                "margin: 0",
-                "overflow: visible",
-                "clip: auto",
-                "white-space: normal",
-            ]),
+            Self::SrOnly => vec![
+                Declaration::new("position", "absolute"),
+                Declaration::new("width", "1px"),
+                Declaration::new("height", "1px"),
+                Declaration::new("padding", "0"),
+                Declaration::new("margin", "-1px"),
+                Declaration::new("overflow", "hidden"),
+                Declaration::new("clip", "rect(0, 0, 0, 0)"),
+                Declaration::new("white-space", "nowrap"),
+                Declaration::new("border-width", "0"),
+            ],
+            Self::NotSrOnly => vec![
+                Declaration::new("position", "static"),
+                Declaration::new("width", "auto"),
+                Declaration::new("height", "auto"),
+                Declaration::new("padding", "0"),
+                Declaration::new("margin", "0"),
+                Declaration::new("overflow", "visible"),
+                Declaration::new("clip", "auto"),
+                Declaration::new("white-space", "normal"),
+            ],
         }
     }
 }
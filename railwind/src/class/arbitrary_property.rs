@@ -0,0 +1,103 @@
+use nom::combinator::map_opt;
+use nom::IResult;
+
+use crate::class::utils::arbitrary;
+use crate::class::IntoDeclaration;
+use crate::css::Declaration;
+
+/// Any property and value, written like `[mask-type:luminance]`
+#[derive(Debug, PartialEq, Hash)]
+pub struct ArbitraryProperty<'a> {
+    pub property: &'a str,
+    pub value: &'a str,
+}
+
+pub fn arbitrary_property(input: &str) -> IResult<&str, ArbitraryProperty<'_>> {
+    map_opt(arbitrary, |v: &str| {
+        let (property, value) = v.split_once(':')?;
+        let value = value.trim();
+
+        if is_property(property) && !value.is_empty() && parens_are_balanced(value) {
+            Some(ArbitraryProperty { property, value })
+        } else {
+            None
+        }
+    })(input)
+}
+
+impl<'a> IntoDeclaration for ArbitraryProperty<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new(self.property, self.value)]
+    }
+}
+
+// Like `mask-type`, `-webkit-line-clamp` or `--scroll-offset`
+fn is_property(property: &str) -> bool {
+    let name = property
+        .strip_prefix("--")
+        .or_else(|| property.strip_prefix('-'))
+        .unwrap_or(property);
+
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn parens_are_balanced(value: &str) -> bool {
+    let mut depth = 0;
+
+    for c in value.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return false,
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+
+    depth == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary_property() {
+        assert_eq!(
+            arbitrary_property("[mask-type:luminance]"),
+            Ok((
+                "",
+                ArbitraryProperty {
+                    property: "mask-type",
+                    value: "luminance"
+                }
+            ))
+        );
+        assert_eq!(
+            arbitrary_property("[--scroll-offset:56px]"),
+            Ok((
+                "",
+                ArbitraryProperty {
+                    property: "--scroll-offset",
+                    value: "56px"
+                }
+            ))
+        );
+        assert_eq!(
+            arbitrary_property("[clip-path:inset(0 0 0 0)]"),
+            Ok((
+                "",
+                ArbitraryProperty {
+                    property: "clip-path",
+                    value: "inset(0 0 0 0)"
+                }
+            ))
+        );
+        assert!(arbitrary_property("[mask-type]").is_err());
+        assert!(arbitrary_property("[mask type:luminance]").is_err());
+        assert!(arbitrary_property("[:luminance]").is_err());
+        assert!(arbitrary_property("[width:calc(100%]").is_err());
+    }
+}
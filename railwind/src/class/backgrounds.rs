@@ -1,13 +1,15 @@
-use macro_derive::{ConfigurableEnumParser, ConfigurableParser, EnumParser, IntoDeclaration};
+use macro_derive::{EnumParser, IntoDeclaration};
 use nom::branch::alt;
-use nom::bytes::complete::tag;
-use nom::combinator::map;
-use nom::sequence::preceded;
+use nom::bytes::complete::{is_not, tag};
+use nom::combinator::{map, verify};
+use nom::sequence::{delimited, preceded};
 use nom::IResult;
 
-use crate::class::colors::hex_color;
-use crate::class::{Decl, IntoDeclaration};
+use crate::class::colors::{color, hex_color, is_color, Color};
+use crate::class::utils::{arbitrary, hashmap_value, strip_hint};
+use crate::class::IntoDeclaration;
 use crate::config::Config;
+use crate::css::Declaration;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Backgrounds<'a> {
@@ -28,13 +30,16 @@ pub fn backgrounds<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, B
             tag("bg-"),
             alt((
                 map(attachment, Backgrounds::BackgroundAttachment),
-                map(clip, Backgrounds::BackgroundClip),
-                map(|i| color(i, config), Backgrounds::BackgroundColor),
-                map(origin, Backgrounds::BackgroundOrigin),
-                map(|i| position(i, config), Backgrounds::BackgroundPosition),
+                preceded(tag("clip-"), map(clip, Backgrounds::BackgroundClip)),
+                preceded(tag("origin-"), map(origin, Backgrounds::BackgroundOrigin)),
                 map(repeat, Backgrounds::BackgroundRepeat),
                 map(|i| size(i, config), Backgrounds::BackgroundSize),
                 map(|i| image(i, config), Backgrounds::BackgroundImage),
+                map(|i| position(i, config), Backgrounds::BackgroundPosition),
+                map(
+                    |i| background_color(i, config),
+                    Backgrounds::BackgroundColor,
+                ),
             )),
         ),
         map(
@@ -45,7 +50,7 @@ pub fn backgrounds<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, B
 }
 
 impl<'a> IntoDeclaration for Backgrounds<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Backgrounds::BackgroundAttachment(b) => b.to_decl(),
             Backgrounds::BackgroundClip(b) => b.to_decl(),
@@ -86,38 +91,42 @@ pub enum BackgroundClip {
 }
 
 impl IntoDeclaration for BackgroundClip {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         let val = match self {
             Self::Border => "border-box",
             Self::Padding => "padding-box",
             Self::Content => "content-box",
             Self::Text => {
-                return Decl::Vec(vec![
-                    "-webkit-background-clip: text".into(),
-                    "background-clip: text".into(),
-                ])
+                return vec![
+                    Declaration::new("-webkit-background-clip", "text"),
+                    Declaration::new("background-clip", "text"),
+                ]
             }
         };
 
-        Decl::String(format!("background-clip: {}", val))
+        vec![Declaration::new("background-clip", val)]
     }
 }
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser)]
-#[name(color)]
-#[config(backgrounds.get_color)]
-pub struct BackgroundColor<'a>(pub &'a str);
+#[derive(Debug, PartialEq, Hash)]
+pub struct BackgroundColor<'a>(pub Color<'a>);
+
+fn background_color<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, BackgroundColor<'a>> {
+    map(color(config.backgrounds.get_color()), BackgroundColor)(input)
+}
 
 impl<'a> IntoDeclaration for BackgroundColor<'a> {
-    fn to_decl(self) -> Decl {
-        if let Ok((_, color)) = hex_color(self.0) {
-            Decl::Double([
-                "--tw-bg-opacity: 1".into(),
-                format!("background-color: rgb({color} / var(--tw-bg-opacity))"),
-            ])
-        } else {
-            return Decl::String(format!("background-color: {}", self.0));
-        }
+    fn to_decl(self) -> Vec<Declaration> {
+        let mut decls: Vec<Declaration> =
+            self.0.opacity_decl("--tw-bg-opacity").into_iter().collect();
+        decls.push(Declaration::new(
+            "background-color",
+            self.0.value(Some("--tw-bg-opacity")),
+        ));
+        decls
     }
 }
 
@@ -136,12 +145,22 @@ pub enum BackgroundOrigin {
     Content,
 }
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
-#[name(position)]
-#[config(backgrounds.get_position)]
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
 #[decl("background-position")]
 pub struct BackgroundPosition<'a>(pub &'a str);
 
+fn position<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, BackgroundPosition<'a>> {
+    map(
+        alt((
+            map(verify(arbitrary, |v: &str| !is_color(v)), |v| {
+                strip_hint(v, "position")
+            }),
+            hashmap_value(config.backgrounds.get_position()),
+        )),
+        BackgroundPosition,
+    )(input)
+}
+
 #[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
 #[name(repeat)]
 #[decl("background-repeat")]
@@ -162,55 +181,98 @@ pub enum BackgroundRepeat {
     RepeatSpace,
 }
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
-#[name(size)]
-#[config(backgrounds.get_size)]
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
 #[decl("background-size")]
 pub struct BackgroundSize<'a>(pub &'a str);
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
-#[name(image)]
-#[config(backgrounds.get_image)]
+fn size<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, BackgroundSize<'a>> {
+    map(
+        alt((
+            delimited(tag("[length:"), is_not("]"), tag("]")),
+            hashmap_value(config.backgrounds.get_size()),
+        )),
+        BackgroundSize,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
 #[decl("background-image")]
 pub struct BackgroundImage<'a>(pub &'a str);
 
-#[derive(Debug, PartialEq, Hash, ConfigurableEnumParser)]
-#[name(gradient_color_stops)]
-#[config(backgrounds.get_gradient_color_stops)]
+fn image<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, BackgroundImage<'a>> {
+    map(
+        alt((
+            verify(arbitrary, |v: &str| v.starts_with("url(")),
+            hashmap_value(config.backgrounds.get_image()),
+        )),
+        BackgroundImage,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash)]
 pub enum GradientColorStops<'a> {
-    #[tag("from")]
-    From(&'a str),
-    #[tag("to")]
-    To(&'a str),
-    #[tag("via")]
-    Via(&'a str),
+    From(Color<'a>),
+    To(Color<'a>),
+    Via(Color<'a>),
+}
+
+fn gradient_color_stops<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, GradientColorStops<'a>> {
+    let stop = || color(config.backgrounds.get_gradient_color_stops());
+
+    alt((
+        map(preceded(tag("from-"), stop()), GradientColorStops::From),
+        map(preceded(tag("to-"), stop()), GradientColorStops::To),
+        map(preceded(tag("via-"), stop()), GradientColorStops::Via),
+    ))(input)
 }
 
 impl<'a> IntoDeclaration for GradientColorStops<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::From(g) => Decl::Vec(vec![
-                format!("--tw-gradient-from: {}", g),
-                format!(
-                    "--tw-gradient-to: rgb({}) / 0",
-                    hex_color(g)
-                        .map(|(_, color)| color)
-                        .unwrap_or("0 0 0".to_string())
+            Self::From(g) => vec![
+                Declaration::new("--tw-gradient-from", g.value(None)),
+                Declaration::new(
+                    "--tw-gradient-to",
+                    format!("rgb({} / 0)", transparent_stop(g.value)),
+                ),
+                Declaration::new(
+                    "--tw-gradient-stops",
+                    "var(--tw-gradient-from), var(--tw-gradient-to)",
+                ),
+            ],
+            Self::To(g) => vec![Declaration::new("--tw-gradient-to", g.value(None))],
+            Self::Via(g) => vec![
+                Declaration::new(
+                    "--tw-gradient-to",
+                    format!("rgb({} / 0)", transparent_stop(g.value)),
                 ),
-                "--tw-gradient-stops: var(--tw-gradient-from), var(--tw-gradient-to)".into(),
-            ]),
-            Self::To(g) => Decl::String(format!("--tw-gradient-to: {}", g)),
-            Self::Via(g) => Decl::Vec(vec![
-                format!("--tw-gradient-to: {}", g),
-                format!(
-                    "--tw-gradient-stops: var(--tw-gradient-from), {}, var(--tw-gradient-to)",
-                    g
+                Declaration::new(
+                    "--tw-gradient-stops",
+                    format!(
+                        "var(--tw-gradient-from), {}, var(--tw-gradient-to)",
+                        g.value(None)
+                    ),
                 ),
-            ]),
+            ],
         }
     }
 }
 
+// The color the gradient fades out to, before the `to-*` stop is set
+fn transparent_stop(color: &str) -> String {
+    if let Ok((_, hex)) = hex_color(color) {
+        return hex.rgb;
+    }
+
+    match color {
+        "transparent" => "0 0 0".into(),
+        _ => "255 255 255".into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,7 +291,7 @@ mod tests {
     #[test]
     fn test_clip() {
         assert_eq!(
-            backgrounds("bg-content", &Config::default()),
+            backgrounds("bg-clip-content", &Config::default()),
             Ok(("", Backgrounds::BackgroundClip(BackgroundClip::Content)))
         );
     }
@@ -238,7 +300,32 @@ mod tests {
     fn test_color() {
         assert_eq!(
             backgrounds("bg-red-500", &Config::default()),
-            Ok(("", Backgrounds::BackgroundColor(BackgroundColor("#ef4444"))))
+            Ok((
+                "",
+                Backgrounds::BackgroundColor(BackgroundColor(Color::new("#ef4444")))
+            ))
+        );
+        assert_eq!(
+            backgrounds("bg-red-500/50", &Config::default()),
+            Ok((
+                "",
+                Backgrounds::BackgroundColor(BackgroundColor(Color::with_opacity("#ef4444", "50")))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_position_and_size() {
+        assert_eq!(
+            backgrounds("bg-[position:center_top]", &Config::default()),
+            Ok((
+                "",
+                Backgrounds::BackgroundPosition(BackgroundPosition("center_top"))
+            ))
+        );
+        assert_eq!(
+            backgrounds("bg-[length:20px]", &Config::default()),
+            Ok(("", Backgrounds::BackgroundSize(BackgroundSize("20px"))))
         );
     }
 
@@ -246,11 +333,16 @@ mod tests {
     fn test_config() {
         let mut c = Config::default();
 
-        c.backgrounds.get_mut_color().insert("yellow", "#yellow");
+        c.backgrounds
+            .get_mut_color()
+            .insert("yellow".into(), "#yellow".into());
 
         assert_eq!(
             backgrounds("bg-yellow", &c),
-            Ok(("", Backgrounds::BackgroundColor(BackgroundColor("#yellow"))))
+            Ok((
+                "",
+                Backgrounds::BackgroundColor(BackgroundColor(Color::new("#yellow")))
+            ))
         );
     }
 }
@@ -1,14 +1,17 @@
 use macro_derive::{ConfigurableParser, EnumParser, IntoDeclaration};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::combinator::{map, map_opt, value};
+use nom::combinator::{eof, map, map_opt, verify};
 use nom::sequence::preceded;
 use nom::IResult;
 
-use crate::class::colors::hex_color;
-use crate::class::utils::{arbitrary_hashmap_value, keyword_value};
-use crate::class::{Decl, IntoDeclaration};
+use crate::class::colors::{color, is_color, Color};
+use crate::class::utils::{
+    arbitrary_hashmap_value, keyword_dash, keyword_value_or_default, strip_hint,
+};
+use crate::class::IntoDeclaration;
 use crate::config::Config;
+use crate::css::Declaration;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Borders<'a> {
@@ -39,47 +42,80 @@ pub fn borders<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Borde
             tag("border"),
             alt((
                 map(|i| border_width(i, config), Borders::BorderWidth),
-                map(|i| border_color(i, config), Borders::BorderColor),
-                map(border_style, Borders::BorderStyle),
+                preceded(
+                    tag("-"),
+                    alt((
+                        map(border_style, Borders::BorderStyle),
+                        map(|i| border_color(i, config), Borders::BorderColor),
+                    )),
+                ),
             )),
         ),
         preceded(
-            tag("divide-"),
+            tag("divide"),
             alt((
-                map(|i| divide_width(i, config), Borders::DivideWidth),
+                preceded(
+                    tag("-"),
+                    alt((
+                        map(|i| divide_width(i, config), Borders::DivideWidth),
+                        map(divide_style, Borders::DivideStyle),
+                    )),
+                ),
                 map(|i| divide_color(i, config), Borders::DivideColor),
-                map(divide_style, Borders::DivideStyle),
             )),
         ),
         preceded(
-            tag("outline-"),
+            tag("outline"),
             alt((
+                preceded(
+                    tag("-offset"),
+                    map(|i| outline_offset(i, config), Borders::OutlineOffset),
+                ),
+                preceded(tag("-"), map(outline_style, Borders::OutlineStyle)),
                 map(|i| outline_width(i, config), Borders::OutlineWidth),
                 map(|i| outline_color(i, config), Borders::OutlineColor),
-                map(outline_style, Borders::OutlineStyle),
-                map(|i| outline_offset(i, config), Borders::OutlineOffset),
+                map(eof, |_| Borders::OutlineStyle(OutlineStyle::Solid)),
             )),
         ),
-        map(tag("outline"), |_| {
-            Borders::OutlineStyle(OutlineStyle::Solid)
-        }),
         preceded(
             tag("ring"),
             alt((
-                map(|i| ring_width(i, config), Borders::RingWidth),
-                preceded(tag("-"), map(|i| ring_color(i, config), Borders::RingColor)),
                 preceded(
-                    tag("offset-"),
+                    tag("-offset"),
                     alt((
                         map(|i| ring_offset_width(i, config), Borders::RingOffsetWidth),
                         map(|i| ring_offset_color(i, config), Borders::RingOffsetColor),
                     )),
                 ),
+                map(|i| ring_width(i, config), Borders::RingWidth),
+                map(|i| ring_color(i, config), Borders::RingColor),
             )),
         ),
     ))(input)
 }
 
+impl<'a> IntoDeclaration for Borders<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::BorderRadius(b) => b.to_decl(),
+            Self::BorderWidth(b) => b.to_decl(),
+            Self::BorderColor(b) => b.to_decl(),
+            Self::BorderStyle(b) => b.to_decl(),
+            Self::DivideWidth(b) => b.to_decl(),
+            Self::DivideColor(b) => b.to_decl(),
+            Self::DivideStyle(b) => b.to_decl(),
+            Self::OutlineWidth(b) => b.to_decl(),
+            Self::OutlineColor(b) => b.to_decl(),
+            Self::OutlineStyle(b) => b.to_decl(),
+            Self::OutlineOffset(b) => b.to_decl(),
+            Self::RingWidth(b) => b.to_decl(),
+            Self::RingColor(b) => b.to_decl(),
+            Self::RingOffsetWidth(b) => b.to_decl(),
+            Self::RingOffsetColor(b) => b.to_decl(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Hash)]
 pub enum BorderRadius<'a> {
     Around(&'a str),
@@ -100,45 +136,57 @@ fn border_radius<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Bor
         preceded(
             tag("-"),
             alt((
-                map(keyword_value("t", radius), BorderRadius::Top),
-                map(keyword_value("r", radius), BorderRadius::Right),
-                map(keyword_value("b", radius), BorderRadius::Right),
-                map(keyword_value("l", radius), BorderRadius::Right),
-                map(keyword_value("tl", radius), BorderRadius::Right),
-                map(keyword_value("tr", radius), BorderRadius::Right),
-                map(keyword_value("br", radius), BorderRadius::Right),
-                map(keyword_value("bl", radius), BorderRadius::Right),
+                map(keyword_value_or_default("t", radius), BorderRadius::Top),
+                map(keyword_value_or_default("r", radius), BorderRadius::Right),
+                map(keyword_value_or_default("b", radius), BorderRadius::Bottom),
+                map(keyword_value_or_default("l", radius), BorderRadius::Left),
+                map(
+                    keyword_value_or_default("tl", radius),
+                    BorderRadius::TopLeft,
+                ),
+                map(
+                    keyword_value_or_default("tr", radius),
+                    BorderRadius::TopRight,
+                ),
+                map(
+                    keyword_value_or_default("br", radius),
+                    BorderRadius::BottomRight,
+                ),
+                map(
+                    keyword_value_or_default("bl", radius),
+                    BorderRadius::BottomLeft,
+                ),
                 map(arbitrary_hashmap_value(radius), BorderRadius::Around),
             )),
         ),
-        map_opt(tag(""), |_| radius.get("").map(|b| BorderRadius::Around(b))),
+        map_opt(eof, |_| radius.get("").map(|b| BorderRadius::Around(b))),
     ))(input)
 }
 
 impl<'a> IntoDeclaration for BorderRadius<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::Around(r) => Decl::String(format!("border-radius: {}", r)),
-            Self::Top(r) => Decl::Vec(vec![
-                format!("border-top-left-radius: {}", r),
-                format!("border-top-right-radius: {}", r),
-            ]),
-            Self::Right(r) => Decl::Vec(vec![
-                format!("border-top-right-radius: {}", r),
-                format!("border-bottom-right-radius: {}", r),
-            ]),
-            Self::Bottom(r) => Decl::Vec(vec![
-                format!("border-bottom-right-radius: {}", r),
-                format!("border-bottom-left-radius: {}", r),
-            ]),
-            Self::Left(r) => Decl::Vec(vec![
-                format!("border-top-left-radius: {}", r),
-                format!("border-bottom-left-radius: {}", r),
-            ]),
-            Self::TopLeft(r) => Decl::String(format!("border-top-left-radius: {}", r)),
-            Self::TopRight(r) => Decl::String(format!("border-top-right-radius: {}", r)),
-            Self::BottomRight(r) => Decl::String(format!("border-bottom-right-radius: {}", r)),
-            Self::BottomLeft(r) => Decl::String(format!("border-bottom-left-radius: {}", r)),
+            Self::Around(r) => vec![Declaration::new("border-radius", r)],
+            Self::Top(r) => vec![
+                Declaration::new("border-top-left-radius", r),
+                Declaration::new("border-top-right-radius", r),
+            ],
+            Self::Right(r) => vec![
+                Declaration::new("border-top-right-radius", r),
+                Declaration::new("border-bottom-right-radius", r),
+            ],
+            Self::Bottom(r) => vec![
+                Declaration::new("border-bottom-right-radius", r),
+                Declaration::new("border-bottom-left-radius", r),
+            ],
+            Self::Left(r) => vec![
+                Declaration::new("border-top-left-radius", r),
+                Declaration::new("border-bottom-left-radius", r),
+            ],
+            Self::TopLeft(r) => vec![Declaration::new("border-top-left-radius", r)],
+            Self::TopRight(r) => vec![Declaration::new("border-top-right-radius", r)],
+            Self::BottomRight(r) => vec![Declaration::new("border-bottom-right-radius", r)],
+            Self::BottomLeft(r) => vec![Declaration::new("border-bottom-left-radius", r)],
         }
     }
 }
@@ -156,133 +204,112 @@ pub enum BorderWidth<'a> {
 
 fn border_width<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, BorderWidth<'a>> {
     let width = config.borders.get_border_width();
+    // Arbitrary colors share the `border-` prefix, so they are left for `border_color`
+    let side = |keyword| {
+        map(
+            verify(keyword_value_or_default(keyword, width), |w: &str| {
+                !is_color(w)
+            }),
+            |w| strip_hint(w, "length"),
+        )
+    };
 
     alt((
         preceded(
             tag("-"),
             alt((
-                map(keyword_value("x", width), BorderWidth::X),
-                map(keyword_value("y", width), BorderWidth::Y),
-                map(keyword_value("t", width), BorderWidth::Top),
-                map(keyword_value("r", width), BorderWidth::Right),
-                map(keyword_value("b", width), BorderWidth::Bottom),
-                map(keyword_value("l", width), BorderWidth::Left),
-                map(arbitrary_hashmap_value(width), BorderWidth::Around),
+                map(side("x"), BorderWidth::X),
+                map(side("y"), BorderWidth::Y),
+                map(side("t"), BorderWidth::Top),
+                map(side("r"), BorderWidth::Right),
+                map(side("b"), BorderWidth::Bottom),
+                map(side("l"), BorderWidth::Left),
+                map(
+                    verify(arbitrary_hashmap_value(width), |w: &str| !is_color(w)),
+                    |w| BorderWidth::Around(strip_hint(w, "length")),
+                ),
             )),
         ),
-        map_opt(tag(""), |_| width.get("").map(|w| BorderWidth::Around(w))),
+        map_opt(eof, |_| width.get("").map(|w| BorderWidth::Around(w))),
     ))(input)
 }
 
 impl<'a> IntoDeclaration for BorderWidth<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::Around(w) => Decl::String(format!("border-width: {}", w)),
-            Self::X(w) => Decl::Vec(vec![
-                format!("border-left-width: {}", w),
-                format!("border-right-width: {}", w),
-            ]),
-            Self::Y(w) => Decl::Vec(vec![
-                format!("border-top-width: {}", w),
-                format!("border-bottom-width: {}", w),
-            ]),
-            Self::Top(w) => Decl::String(format!("border-top-width: {}", w)),
-            Self::Right(w) => Decl::String(format!("border-right-width: {}", w)),
-            Self::Bottom(w) => Decl::String(format!("border-bottom-width: {}", w)),
-            Self::Left(w) => Decl::String(format!("border-left-width: {}", w)),
+            Self::Around(w) => vec![Declaration::new("border-width", w)],
+            Self::X(w) => vec![
+                Declaration::new("border-left-width", w),
+                Declaration::new("border-right-width", w),
+            ],
+            Self::Y(w) => vec![
+                Declaration::new("border-top-width", w),
+                Declaration::new("border-bottom-width", w),
+            ],
+            Self::Top(w) => vec![Declaration::new("border-top-width", w)],
+            Self::Right(w) => vec![Declaration::new("border-right-width", w)],
+            Self::Bottom(w) => vec![Declaration::new("border-bottom-width", w)],
+            Self::Left(w) => vec![Declaration::new("border-left-width", w)],
         }
     }
 }
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum BorderColor<'a> {
-    Around(&'a str),
-    X(&'a str),
-    Y(&'a str),
-    Top(&'a str),
-    Right(&'a str),
-    Bottom(&'a str),
-    Left(&'a str),
+    Around(Color<'a>),
+    X(Color<'a>),
+    Y(Color<'a>),
+    Top(Color<'a>),
+    Right(Color<'a>),
+    Bottom(Color<'a>),
+    Left(Color<'a>),
 }
 
 fn border_color<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, BorderColor<'a>> {
-    let color = config.borders.get_border_color();
+    let side = |keyword| {
+        preceded(
+            keyword_dash(keyword),
+            color(config.borders.get_border_color()),
+        )
+    };
 
     alt((
-        map(keyword_value("x", color), BorderColor::X),
-        map(keyword_value("y", color), BorderColor::Y),
-        map(keyword_value("t", color), BorderColor::Top),
-        map(keyword_value("r", color), BorderColor::Right),
-        map(keyword_value("b", color), BorderColor::Bottom),
-        map(keyword_value("l", color), BorderColor::Left),
-        map(arbitrary_hashmap_value(color), BorderColor::Around),
+        map(side("x"), BorderColor::X),
+        map(side("y"), BorderColor::Y),
+        map(side("t"), BorderColor::Top),
+        map(side("r"), BorderColor::Right),
+        map(side("b"), BorderColor::Bottom),
+        map(side("l"), BorderColor::Left),
+        map(
+            color(config.borders.get_border_color()),
+            BorderColor::Around,
+        ),
     ))(input)
 }
 
 impl<'a> IntoDeclaration for BorderColor<'a> {
-    fn to_decl(self) -> Decl {
-        let value = match self {
-            BorderColor::Around(v) => v,
-            BorderColor::X(v) => v,
-            BorderColor::Y(v) => v,
-            BorderColor::Top(v) => v,
-            BorderColor::Right(v) => v,
-            BorderColor::Bottom(v) => v,
-            BorderColor::Left(v) => v,
+    fn to_decl(self) -> Vec<Declaration> {
+        let (color, properties): (Color, &[&str]) = match self {
+            Self::Around(c) => (c, &["border-color"]),
+            Self::X(c) => (c, &["border-left-color", "border-right-color"]),
+            Self::Y(c) => (c, &["border-top-color", "border-bottom-color"]),
+            Self::Top(c) => (c, &["border-top-color"]),
+            Self::Right(c) => (c, &["border-right-color"]),
+            Self::Bottom(c) => (c, &["border-bottom-color"]),
+            Self::Left(c) => (c, &["border-left-color"]),
         };
 
-        if let Ok((_, color)) = hex_color(&value) {
-            let decl = match self {
-                Self::Around(_) => Decl::Vec(vec![
-                    "--tw-border-opacity: 1".into(),
-                    format!("border-color: rgb({color} / var(--tw-border-opacity))",),
-                ]),
-                Self::X(_) => Decl::Vec(vec![
-                    "--tw-border-opacity: 1".into(),
-                    format!("border-left-color: rgb({color} / var(--tw-border-opacity))",),
-                    format!("border-right-color: rgb({color} / var(--tw-border-opacity))",),
-                ]),
-                Self::Y(_) => Decl::Vec(vec![
-                    "--tw-border-opacity: 1".into(),
-                    format!("border-top-color: rgb({color} / var(--tw-border-opacity))",),
-                    format!("border-bottom-color: rgb({color} / var(--tw-border-opacity))",),
-                ]),
-                Self::Top(_) => Decl::Vec(vec![
-                    "--tw-border-opacity: 1".into(),
-                    format!("border-top-color: rgb({color} / var(--tw-border-opacity))",),
-                ]),
-                Self::Right(_) => Decl::Vec(vec![
-                    "--tw-border-opacity: 1".into(),
-                    format!("border-right-color: rgb({color} / var(--tw-border-opacity))",),
-                ]),
-                Self::Bottom(_) => Decl::Vec(vec![
-                    "--tw-border-opacity: 1".into(),
-                    format!("border-bottom-color: rgb({color} / var(--tw-border-opacity))",),
-                ]),
-                Self::Left(_) => Decl::Vec(vec![
-                    "--tw-border-opacity: 1".into(),
-                    format!("border-left-color: rgb({color} / var(--tw-border-opacity))",),
-                ]),
-            };
-
-            decl
-        } else {
-            match self {
-                Self::Around(_) => Decl::String(format!("border-color: {}", value)),
-                Self::X(_) => Decl::Vec(vec![
-                    format!("border-left-color: {}", value),
-                    format!("border-right-color: {}", value),
-                ]),
-                Self::Y(_) => Decl::Vec(vec![
-                    format!("border-top-color: {}", value),
-                    format!("border-bottom-color: {}", value),
-                ]),
-                Self::Top(_) => Decl::String(format!("border-top-color: {}", value)),
-                Self::Right(_) => Decl::String(format!("border-right-color: {}", value)),
-                Self::Bottom(_) => Decl::String(format!("border-bottom-color: {}", value)),
-                Self::Left(_) => Decl::String(format!("border-left-color: {}", value)),
-            }
+        let mut decls: Vec<Declaration> = color
+            .opacity_decl("--tw-border-opacity")
+            .into_iter()
+            .collect();
+        let value = color.value(Some("--tw-border-opacity"));
+
+        for property in properties {
+            decls.push(Declaration::new(*property, &value));
         }
+
+        decls
     }
 }
 
@@ -316,51 +343,69 @@ fn divide_width<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Divi
     let width = config.borders.get_divide_width();
 
     alt((
-        map(keyword_value("x", width), DivideWidth::X),
-        map(keyword_value("y", width), DivideWidth::Y),
         map(tag("x-reverse"), |_| DivideWidth::ReverseX),
         map(tag("y-reverse"), |_| DivideWidth::ReverseY),
-        map(tag("x"), |_| DivideWidth::X("")),
-        map(tag("y"), |_| DivideWidth::Y("")),
+        map(keyword_value_or_default("x", width), DivideWidth::X),
+        map(keyword_value_or_default("y", width), DivideWidth::Y),
     ))(input)
 }
 
 impl<'a> IntoDeclaration for DivideWidth<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::X(w) => Decl::Vec(vec![
-                "--tw-divide-x-reverse: 0".into(),
-                format!(
-                    "border-right-width: calc({} * var(--tw-divide-x-reverse))",
-                    w
+            Self::X(w) => vec![
+                Declaration::new("--tw-divide-x-reverse", "0"),
+                Declaration::new(
+                    "border-right-width",
+                    format!("calc({} * var(--tw-divide-x-reverse))", w),
                 ),
-                format!(
-                    "border-left-width: calc({} * calc(1 - var(--tw-divide-x-reverse)))",
-                    w
+                Declaration::new(
+                    "border-left-width",
+                    format!("calc({} * calc(1 - var(--tw-divide-x-reverse)))", w),
                 ),
-            ]),
-            Self::Y(w) => Decl::Vec(vec![
-                "--tw-divide-y-reverse: 0".into(),
-                format!(
-                    "border-top-width: calc({} * calc(1 - var(--tw-divide-y-reverse)))",
-                    w
+            ],
+            Self::Y(w) => vec![
+                Declaration::new("--tw-divide-y-reverse", "0"),
+                Declaration::new(
+                    "border-top-width",
+                    format!("calc({} * calc(1 - var(--tw-divide-y-reverse)))", w),
                 ),
-                format!(
-                    "border-bottom-width: calc({} * var(--tw-divide-y-reverse))",
-                    w
+                Declaration::new(
+                    "border-bottom-width",
+                    format!("calc({} * var(--tw-divide-y-reverse))", w),
                 ),
-            ]),
-            Self::ReverseX => Decl::Lit("--tw-divide-x-reverse: 1"),
-            Self::ReverseY => Decl::Lit("--tw-divide-y-reverse: 1"),
+            ],
+            Self::ReverseX => vec![Declaration::new("--tw-divide-x-reverse", "1")],
+            Self::ReverseY => vec![Declaration::new("--tw-divide-y-reverse", "1")],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
-#[name(divide_color)]
-#[config(borders.get_divide_color)]
-#[decl("border-color")]
-pub struct DivideColor<'a>(pub &'a str);
+#[derive(Debug, PartialEq, Hash)]
+pub struct DivideColor<'a>(pub Color<'a>);
+
+fn divide_color<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, DivideColor<'a>> {
+    map(
+        preceded(tag("-"), color(config.borders.get_divide_color())),
+        DivideColor,
+    )(input)
+}
+
+impl<'a> IntoDeclaration for DivideColor<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        let mut decls: Vec<Declaration> = self
+            .0
+            .opacity_decl("--tw-divide-opacity")
+            .into_iter()
+            .collect();
+        decls.push(Declaration::new(
+            "border-color",
+            self.0.value(Some("--tw-divide-opacity")),
+        ));
+
+        decls
+    }
+}
 
 #[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
 #[name(divide_style)]
@@ -384,11 +429,21 @@ pub enum DivideStyle {
 #[decl("outline-width")]
 pub struct OutlineWidth<'a>(pub &'a str);
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
-#[name(outline_color)]
-#[config(borders.get_outline_color)]
-#[decl("outline-color")]
-pub struct OutlineColor<'a>(pub &'a str);
+#[derive(Debug, PartialEq, Hash)]
+pub struct OutlineColor<'a>(pub Color<'a>);
+
+fn outline_color<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, OutlineColor<'a>> {
+    map(
+        preceded(tag("-"), color(config.borders.get_outline_color())),
+        OutlineColor,
+    )(input)
+}
+
+impl<'a> IntoDeclaration for OutlineColor<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("outline-color", self.0.value(None))]
+    }
+}
 
 #[derive(Debug, PartialEq, Hash, EnumParser)]
 #[name(outline_style)]
@@ -405,13 +460,13 @@ pub enum OutlineStyle {
 }
 
 impl IntoDeclaration for OutlineStyle {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         let val = match self {
             Self::None => {
-                return Decl::Vec(vec![
-                    "outline: 2px solid transparent".into(),
-                    "outline-offset: 2px".into(),
-                ])
+                return vec![
+                    Declaration::new("outline", "2px solid transparent"),
+                    Declaration::new("outline-offset", "2px"),
+                ]
             }
             Self::Solid => "solid",
             Self::Dashed => "dashed",
@@ -419,7 +474,7 @@ impl IntoDeclaration for OutlineStyle {
             Self::Double => "double",
         };
 
-        Decl::String(format!("border-style: {}", val))
+        vec![Declaration::new("outline-style", val)]
     }
 }
 
@@ -442,28 +497,46 @@ fn ring_width<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, RingWi
         preceded(
             tag("-"),
             alt((
-                map(arbitrary_hashmap_value(width), RingWidth::Value),
+                // Arbitrary colors share the `ring-` prefix, so they are left for `ring_color`
+                map(
+                    verify(arbitrary_hashmap_value(width), |w: &str| !is_color(w)),
+                    |w| RingWidth::Value(strip_hint(w, "length")),
+                ),
                 map(tag("inset"), |_| RingWidth::Inset),
             )),
         ),
-        map_opt(tag(""), |_| width.get("").map(|w| RingWidth::Value(w))),
+        map_opt(eof, |_| width.get("").map(|w| RingWidth::Value(w))),
     ))(input)
 }
 
 impl<'a> IntoDeclaration for RingWidth<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::Value(w) => Decl::String(format!("box-shadow: {}", w)),
-            Self::Inset => Decl::Lit("--tw-ring-inset: inset"),
+            Self::Value(w) => vec![
+                Declaration::new("--tw-ring-offset-shadow", "var(--tw-ring-inset) 0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color)"),
+                Declaration::new("--tw-ring-shadow", format!("var(--tw-ring-inset) 0 0 0 calc({w} + var(--tw-ring-offset-width)) var(--tw-ring-color)")),
+                Declaration::new("box-shadow", "var(--tw-ring-offset-shadow), var(--tw-ring-shadow), var(--tw-shadow, 0 0 #0000)"),
+            ],
+            Self::Inset => vec![Declaration::new("--tw-ring-inset", "inset")],
         }
     }
 }
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
-#[name(ring_color)]
-#[config(borders.get_ring_color)]
-#[decl("--tw-ring-color")]
-pub struct RingColor<'a>(pub &'a str);
+#[derive(Debug, PartialEq, Hash)]
+pub struct RingColor<'a>(pub Color<'a>);
+
+fn ring_color<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, RingColor<'a>> {
+    map(
+        preceded(tag("-"), color(config.borders.get_ring_color())),
+        RingColor,
+    )(input)
+}
+
+impl<'a> IntoDeclaration for RingColor<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("--tw-ring-color", self.0.value(None))]
+    }
+}
 
 #[derive(Debug, PartialEq, Hash, ConfigurableParser)]
 #[name(ring_offset_width)]
@@ -471,24 +544,32 @@ pub struct RingColor<'a>(pub &'a str);
 pub struct RingOffsetWidth<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for RingOffsetWidth<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-ring-offset-width: {}", self.0),
-            "box-shadow: 0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color), var(--tw-ring-shadow)".into(),
-        ])
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-ring-offset-width", self.0),
+            Declaration::new("box-shadow", "0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color), var(--tw-ring-shadow)"),
+        ]
     }
 }
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser)]
-#[name(ring_offset_color)]
-#[config(borders.get_ring_offset_color)]
-pub struct RingOffsetColor<'a>(pub &'a str);
+#[derive(Debug, PartialEq, Hash)]
+pub struct RingOffsetColor<'a>(pub Color<'a>);
+
+fn ring_offset_color<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, RingOffsetColor<'a>> {
+    map(
+        preceded(tag("-"), color(config.borders.get_ring_offset_color())),
+        RingOffsetColor,
+    )(input)
+}
 
 impl<'a> IntoDeclaration for RingOffsetColor<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-ring-offset-color: {}", self.0),
-            "box-shadow: 0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color), var(--tw-ring-shadow)".into(),
-        ])
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-ring-offset-color", self.0.value(None)),
+            Declaration::new("box-shadow", "0 0 0 var(--tw-ring-offset-width) var(--tw-ring-offset-color), var(--tw-ring-shadow)"),
+        ]
     }
 }
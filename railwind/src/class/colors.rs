@@ -1,16 +1,21 @@
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while_m_n};
-use nom::combinator::{map, map_res};
+use nom::combinator::{all_consuming, map, map_res};
+use nom::error::{Error, ErrorKind};
 use nom::sequence::{preceded, tuple};
 use nom::IResult;
+use std::collections::HashMap;
 use std::num::ParseIntError;
 
+use crate::class::utils::{arbitrary_hashmap_value, strip_hint};
+use crate::css::Declaration;
+
 fn from_hex(input: &str) -> Result<u8, ParseIntError> {
     u8::from_str_radix(input, 16)
 }
 
 fn is_hex_digit(c: char) -> bool {
-    c.is_digit(16)
+    c.is_ascii_hexdigit()
 }
 
 fn hex_primary(input: &str) -> IResult<&str, u8> {
@@ -23,33 +28,356 @@ fn hex_single(input: &str) -> IResult<&str, u8> {
     })(input)
 }
 
-pub fn hex_color(input: &str) -> IResult<&str, String> {
-    preceded(
+/// The channels of a hex color, like `255 0 0`, and its alpha if it has one,
+/// like the `0.501961` of `#ff000080`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexColor {
+    pub rgb: String,
+    pub alpha: Option<String>,
+}
+
+impl HexColor {
+    fn new((red, green, blue): (u8, u8, u8), alpha: Option<u8>) -> Self {
+        Self {
+            rgb: format!("{red} {green} {blue}"),
+            alpha: alpha.map(|alpha| round(alpha as f64 / 255.0)),
+        }
+    }
+}
+
+/// Parses a whole `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` color
+pub fn hex_color(input: &str) -> IResult<&str, HexColor> {
+    all_consuming(preceded(
         tag("#"),
         alt((
             map(
-                tuple((hex_primary, hex_primary, hex_primary)),
-                |(red, green, blue)| format!("{red} {green} {blue}"),
+                tuple((hex_primary, hex_primary, hex_primary, hex_primary)),
+                |(red, green, blue, alpha)| HexColor::new((red, green, blue), Some(alpha)),
             ),
+            map(tuple((hex_primary, hex_primary, hex_primary)), |rgb| {
+                HexColor::new(rgb, None)
+            }),
             map(
-                tuple((hex_single, hex_single, hex_single)),
-                |(red, green, blue)| format!("{red} {green} {blue}"),
+                tuple((hex_single, hex_single, hex_single, hex_single)),
+                |(red, green, blue, alpha)| HexColor::new((red, green, blue), Some(alpha)),
             ),
+            map(tuple((hex_single, hex_single, hex_single)), |rgb| {
+                HexColor::new(rgb, None)
+            }),
         )),
-    )(input)
+    ))(input)
+}
+
+/// Checks if an arbitrary value is a color, rather than a position, size, etc.
+pub fn is_color(value: &str) -> bool {
+    ["#", "rgb(", "rgba(", "hsl(", "hsla(", "color:"]
+        .iter()
+        .any(|prefix| value.starts_with(prefix))
+}
+
+/// A color from the theme or an arbitrary value, with the opacity modifier that
+/// followed it, like the `50` in `bg-red-500/50` or the `[--my-alpha]` in `text-current/[--my-alpha]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color<'a> {
+    pub value: &'a str,
+    pub opacity: Option<&'a str>,
+}
+
+impl<'a> Color<'a> {
+    pub fn new(value: &'a str) -> Self {
+        Self {
+            value,
+            opacity: None,
+        }
+    }
+
+    pub fn with_opacity(value: &'a str, opacity: &'a str) -> Self {
+        Self {
+            value,
+            opacity: Some(opacity),
+        }
+    }
+
+    /// The `--tw-*-opacity` that a hex color without a modifier is printed with,
+    /// which is the alpha of the color, or `1` if it doesn't have one
+    pub fn opacity_decl(&self, opacity_var: &str) -> Option<Declaration> {
+        match hex_color(self.value) {
+            Ok((_, hex)) if self.opacity.is_none() => Some(Declaration::new(
+                opacity_var,
+                hex.alpha.unwrap_or_else(|| "1".into()),
+            )),
+            _ => None,
+        }
+    }
+
+    /// The color with its opacity applied. Hex colors become `rgb()` with the modifier,
+    /// or `opacity_var` if there is none, as their alpha. Colors that can't be split into
+    /// channels, like `currentColor` or `var(--brand)`, are mixed with `transparent` instead.
+    pub fn value(&self, opacity_var: Option<&str>) -> String {
+        let alpha = self.alpha();
+
+        match (hex_color(self.value), alpha, opacity_var) {
+            (Ok((_, hex)), Some(alpha), _) => format!("rgb({} / {alpha})", hex.rgb),
+            (Ok((_, hex)), None, Some(var)) => format!("rgb({} / var({var}))", hex.rgb),
+            (Err(_), Some(alpha), _) => format!(
+                "color-mix(in srgb, {} {}, transparent)",
+                self.value,
+                alpha_to_percentage(&alpha)
+            ),
+            _ => self.value.to_string(),
+        }
+    }
+
+    // `/50` is a percentage, while `/[0.37]` and `/[--my-alpha]` are used as they are
+    fn alpha(&self) -> Option<String> {
+        let opacity = self.opacity?;
+
+        match opacity.strip_prefix('[').and_then(|o| o.strip_suffix(']')) {
+            Some(custom_property) if custom_property.starts_with("--") => {
+                Some(format!("var({custom_property})"))
+            }
+            Some(arbitrary) => Some(arbitrary.to_string()),
+            None => opacity
+                .parse::<f64>()
+                .ok()
+                .map(|percentage| round(percentage / 100.0)),
+        }
+    }
+}
+
+fn alpha_to_percentage(alpha: &str) -> String {
+    if alpha.ends_with('%') {
+        return alpha.to_string();
+    }
+
+    match alpha.parse::<f64>() {
+        Ok(alpha) => format!("{}%", round(alpha * 100.0)),
+        Err(_) => format!("calc({alpha} * 100%)"),
+    }
+}
+
+// Hides floating point noise, like the `0.07000000000000001` that `7.0 / 100.0` gives
+fn round(value: f64) -> String {
+    ((value * 1e6).round() / 1e6).to_string()
+}
+
+/// Parses a color from `hashmap` or an arbitrary color, followed by an optional opacity modifier
+pub fn color<'a>(
+    hashmap: &'a HashMap<String, String>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Color<'a>> {
+    move |input: &'a str| {
+        let (value, opacity) = split_opacity(input);
+        if opacity.is_some_and(|o| !is_opacity(o)) {
+            return Err(nom::Err::Error(Error::new(input, ErrorKind::Verify)));
+        }
+
+        let (rest, value) = all_consuming(arbitrary_hashmap_value(hashmap))(value)?;
+
+        Ok((
+            rest,
+            Color {
+                value: strip_hint(value, "color"),
+                opacity,
+            },
+        ))
+    }
+}
+
+/// The opacity modifiers that can be used, for the warning about one that can't
+pub const OPACITY_VALUES: [&str; 4] = [
+    "0 to 100",
+    "[0 to 1]",
+    "[0% to 100%]",
+    "[--custom-property]",
+];
+
+/// Checks an opacity modifier, like the `50` in `bg-red-500/50` or the `[0.37]` in `bg-sky-500/[0.37]`
+pub fn is_opacity(opacity: &str) -> bool {
+    let in_range = |value: &str, max: f64| {
+        value
+            .parse::<f64>()
+            .is_ok_and(|value| (0.0..=max).contains(&value))
+    };
+
+    match opacity.strip_prefix('[').and_then(|o| o.strip_suffix(']')) {
+        Some(custom_property) if custom_property.starts_with("--") => true,
+        Some(arbitrary) => match arbitrary.strip_suffix('%') {
+            Some(percentage) => in_range(percentage, 100.0),
+            None => in_range(arbitrary, 1.0),
+        },
+        None => in_range(opacity, 100.0),
+    }
+}
+
+// Splits `red-500/50` or `[#fff]/[0.5]` at the last slash outside of square brackets,
+// as long as what follows it is a number or an arbitrary value
+pub fn split_opacity(input: &str) -> (&str, Option<&str>) {
+    let mut depth = 0;
+    let mut slash = None;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '/' if depth == 0 => slash = Some(i),
+            _ => (),
+        }
+    }
+
+    let Some(slash) = slash else {
+        return (input, None);
+    };
+
+    let opacity = &input[slash + 1..];
+    let is_number = !opacity.is_empty()
+        && opacity.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && opacity.parse::<f64>().is_ok();
+    let is_arbitrary = opacity.len() > 2 && opacity.starts_with('[') && opacity.ends_with(']');
+
+    if is_number || is_arbitrary {
+        (&input[..slash], Some(opacity))
+    } else {
+        (input, None)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn hex(rgb: &str, alpha: Option<&str>) -> HexColor {
+        HexColor {
+            rgb: rgb.into(),
+            alpha: alpha.map(Into::into),
+        }
+    }
+
     #[test]
     fn test_hex_color_normal() {
-        assert_eq!(hex_color("#26ad4a"), Ok(("", "38 173 74".to_string())));
+        assert_eq!(hex_color("#26ad4a"), Ok(("", hex("38 173 74", None))));
     }
 
     #[test]
     fn test_hex_color_single() {
-        assert_eq!(hex_color("#234"), Ok(("", "34 51 68".to_string())))
+        assert_eq!(hex_color("#234"), Ok(("", hex("34 51 68", None))))
+    }
+
+    #[test]
+    fn test_hex_color_alpha() {
+        assert_eq!(
+            hex_color("#ff000080"),
+            Ok(("", hex("255 0 0", Some("0.501961"))))
+        );
+        assert_eq!(
+            hex_color("#f008"),
+            Ok(("", hex("255 0 0", Some("0.533333"))))
+        );
+        assert!(hex_color("#12345").is_err());
+        assert!(hex_color("#1234567").is_err());
+    }
+
+    #[test]
+    fn test_color_opacity() {
+        let colors = HashMap::from([
+            ("red-500".to_string(), "#ef4444".to_string()),
+            ("current".to_string(), "currentColor".to_string()),
+        ]);
+
+        assert_eq!(color(&colors)("red-500"), Ok(("", Color::new("#ef4444"))));
+        assert_eq!(
+            color(&colors)("red-500/50"),
+            Ok(("", Color::with_opacity("#ef4444", "50")))
+        );
+        assert_eq!(
+            color(&colors)("[#50d71e]/[0.37]"),
+            Ok(("", Color::with_opacity("#50d71e", "[0.37]")))
+        );
+        assert_eq!(
+            color(&colors)("[url(a/b)]"),
+            Ok(("", Color::new("url(a/b)")))
+        );
+        assert_eq!(
+            color(&colors)("[color:var(--brand)]/50"),
+            Ok(("", Color::with_opacity("var(--brand)", "50")))
+        );
+        assert!(color(&colors)("red-500/101").is_err());
+        assert!(color(&colors)("red-500/[abc]").is_err());
+        assert!(color(&colors)("red-500/abc").is_err());
+        assert!(color(&colors)("red-500/").is_err());
+    }
+
+    #[test]
+    fn test_is_opacity() {
+        for opacity in [
+            "0",
+            "50",
+            "100",
+            "7.5",
+            "[0.37]",
+            "[1]",
+            "[50%]",
+            "[--my-alpha]",
+        ] {
+            assert!(is_opacity(opacity), "{opacity}");
+        }
+        for opacity in ["101", "[1.5]", "[101%]", "[abc]", "[-0.5]", "[]"] {
+            assert!(!is_opacity(opacity), "{opacity}");
+        }
+    }
+
+    #[test]
+    fn test_color_value() {
+        assert_eq!(Color::new("#ef4444").value(None), "#ef4444");
+        assert_eq!(
+            Color::new("#ef4444").value(Some("--tw-bg-opacity")),
+            "rgb(239 68 68 / var(--tw-bg-opacity))"
+        );
+        assert_eq!(
+            Color::with_opacity("#ef4444", "50").value(Some("--tw-bg-opacity")),
+            "rgb(239 68 68 / 0.5)"
+        );
+        assert_eq!(
+            Color::with_opacity("#ef4444", "[0.37]").value(None),
+            "rgb(239 68 68 / 0.37)"
+        );
+        assert_eq!(
+            Color::with_opacity("#ef4444", "[--my-alpha]").value(None),
+            "rgb(239 68 68 / var(--my-alpha))"
+        );
+        assert_eq!(
+            Color::with_opacity("currentColor", "25").value(None),
+            "color-mix(in srgb, currentColor 25%, transparent)"
+        );
+        assert_eq!(
+            Color::with_opacity("currentColor", "[0.07]").value(None),
+            "color-mix(in srgb, currentColor 7%, transparent)"
+        );
+        assert_eq!(
+            Color::with_opacity("var(--brand)", "[--my-alpha]").value(None),
+            "color-mix(in srgb, var(--brand) calc(var(--my-alpha) * 100%), transparent)"
+        );
+        assert_eq!(Color::new("currentColor").value(None), "currentColor");
+    }
+
+    #[test]
+    fn test_color_alpha() {
+        let color = Color::new("#ff000080");
+        assert_eq!(
+            color.opacity_decl("--tw-bg-opacity"),
+            Some(Declaration::new("--tw-bg-opacity", "0.501961"))
+        );
+        assert_eq!(
+            color.value(Some("--tw-bg-opacity")),
+            "rgb(255 0 0 / var(--tw-bg-opacity))"
+        );
+
+        let color = Color::with_opacity("#f008", "25");
+        assert_eq!(color.opacity_decl("--tw-bg-opacity"), None);
+        assert_eq!(color.value(Some("--tw-bg-opacity")), "rgb(255 0 0 / 0.25)");
+
+        assert_eq!(
+            Color::new("#ef4444").opacity_decl("--tw-bg-opacity"),
+            Some(Declaration::new("--tw-bg-opacity", "1"))
+        );
     }
 }
@@ -1,13 +1,14 @@
 use macro_derive::{ConfigurableParser, EnumParser, IntoDeclaration};
+use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::combinator::map;
 use nom::sequence::preceded;
 use nom::IResult;
-use nom::{branch::alt, combinator::map_opt};
 
+use crate::class::colors::{color, Color};
+use crate::class::IntoDeclaration;
 use crate::config::Config;
-
-use super::utils::{arbitrary_hashmap_value, keyword_value};
+use crate::css::Declaration;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Effects<'a> {
@@ -28,7 +29,7 @@ pub fn effects<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Effec
             )),
         ),
         preceded(
-            tag("opacity-"),
+            tag("opacity"),
             map(|i| opacity(i, config), Effects::Opacity),
         ),
         preceded(
@@ -42,17 +43,71 @@ pub fn effects<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Effec
     ))(input)
 }
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
+impl<'a> IntoDeclaration for Effects<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::BoxShadow(e) => e.to_decl(),
+            Self::BoxShadowColor(e) => e.to_decl(),
+            Self::Opacity(e) => e.to_decl(),
+            Self::MixBlendMode(e) => e.to_decl(),
+            Self::BackgroundBlendMode(e) => e.to_decl(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, ConfigurableParser)]
 #[name(box_shadow)]
 #[config(effects.get_box_shadow)]
-#[decl("box-shadow")]
 pub struct BoxShadow<'a>(pub &'a str);
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
-#[name(box_shadow_color)]
-#[config(effects.get_box_shadow_color)]
-#[decl("--tw-shadow-color")]
-pub struct BoxShadowColor<'a>(pub &'a str);
+impl<'a> IntoDeclaration for BoxShadow<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-shadow", self.0),
+            Declaration::new("--tw-shadow-colored", colored_shadow(self.0)),
+            Declaration::new("box-shadow", "var(--tw-ring-offset-shadow, 0 0 #0000), var(--tw-ring-shadow, 0 0 #0000), var(--tw-shadow)"),
+        ]
+    }
+}
+
+// Swaps every `rgb(..)` color in a shadow for the `--tw-shadow-color` set by `shadow-{color}`
+fn colored_shadow(shadow: &str) -> String {
+    let mut colored = String::new();
+    let mut rest = shadow;
+
+    while let Some(start) = rest.find("rgb(") {
+        colored.push_str(&rest[..start]);
+        colored.push_str("var(--tw-shadow-color)");
+
+        rest = &rest[start..];
+        rest = rest.find(')').map_or("", |end| &rest[end + 1..]);
+    }
+
+    colored.push_str(rest);
+    colored
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct BoxShadowColor<'a>(pub Color<'a>);
+
+fn box_shadow_color<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, BoxShadowColor<'a>> {
+    map(
+        preceded(tag("-"), color(config.effects.get_box_shadow_color())),
+        BoxShadowColor,
+    )(input)
+}
+
+impl<'a> IntoDeclaration for BoxShadowColor<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-shadow-color", self.0.value(None)),
+            Declaration::new("--tw-shadow", "var(--tw-shadow-colored)"),
+        ]
+    }
+}
 
 #[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
 #[name(opacity)]
@@ -137,3 +192,17 @@ pub enum BackgroundBlendMode {
     #[tag("luminosity")]
     Luminosity,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colored_shadow() {
+        assert_eq!(
+            colored_shadow("0 1px 3px 0 rgb(0 0 0 / 0.1), 0 1px 2px -1px rgb(0 0 0 / 0.1)"),
+            "0 1px 3px 0 var(--tw-shadow-color), 0 1px 2px -1px var(--tw-shadow-color)"
+        );
+        assert_eq!(colored_shadow("0 0 #0000"), "0 0 #0000");
+    }
+}
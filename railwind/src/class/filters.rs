@@ -5,15 +5,15 @@ use nom::combinator::map;
 use nom::sequence::preceded;
 use nom::IResult;
 
-use crate::class::Decl;
+use crate::css::Declaration;
 
 use crate::class::utils::neg_keyword_value;
 use crate::class::IntoDeclaration;
 use crate::config::Config;
 
-const FILTER_STYLE: &str = "filter: var(--tw-blur) var(--tw-brightness) var(--tw-contrast) var(--tw-grayscale) var(--tw-hue-rotate) var(--tw-invert) var(--tw-saturate) var(--tw-sepia) var(--tw-drop-shadow)";
-const WEBKIT_BACKDROP_FILTER_STYLE: &str = "-webkit-backdrop-filter: var(--tw-backdrop-blur) var(--tw-backdrop-brightness) var(--tw-backdrop-contrast) var(--tw-backdrop-grayscale) var(--tw-backdrop-hue-rotate) var(--tw-backdrop-invert) var(--tw-backdrop-opacity) var(--tw-backdrop-saturate) var(--tw-backdrop-sepia)";
-const BACKDROP_FILTER_STYLE: &str = "        backdrop-filter: var(--tw-backdrop-blur) var(--tw-backdrop-brightness) var(--tw-backdrop-contrast) var(--tw-backdrop-grayscale) var(--tw-backdrop-hue-rotate) var(--tw-backdrop-invert) var(--tw-backdrop-opacity) var(--tw-backdrop-saturate) var(--tw-backdrop-sepia)";
+const FILTER_STYLE: (&str, &str) = ("filter", "var(--tw-blur) var(--tw-brightness) var(--tw-contrast) var(--tw-grayscale) var(--tw-hue-rotate) var(--tw-invert) var(--tw-saturate) var(--tw-sepia) var(--tw-drop-shadow)");
+const WEBKIT_BACKDROP_FILTER_STYLE: (&str, &str) = ("-webkit-backdrop-filter", "var(--tw-backdrop-blur) var(--tw-backdrop-brightness) var(--tw-backdrop-contrast) var(--tw-backdrop-grayscale) var(--tw-backdrop-hue-rotate) var(--tw-backdrop-invert) var(--tw-backdrop-opacity) var(--tw-backdrop-saturate) var(--tw-backdrop-sepia)");
+const BACKDROP_FILTER_STYLE: (&str, &str) = ("backdrop-filter", "var(--tw-backdrop-blur) var(--tw-backdrop-brightness) var(--tw-backdrop-contrast) var(--tw-backdrop-grayscale) var(--tw-backdrop-hue-rotate) var(--tw-backdrop-invert) var(--tw-backdrop-opacity) var(--tw-backdrop-saturate) var(--tw-backdrop-sepia)");
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Filter<'a> {
@@ -63,6 +63,11 @@ pub fn filter<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Filter
             map(|i| saturate(i, config), Filter::Saturate),
         ),
         preceded(tag("sepia"), map(|i| sepia(i, config), Filter::Sepia)),
+        // Unlike the other backdrop filters, hue rotate can be negative, like `-backdrop-hue-rotate-15`
+        map(
+            neg_keyword_value("backdrop-hue-rotate", config.filters.get_hue_rotate()),
+            |v| Filter::BackdropHueRotate(BackdropHueRotate(v)),
+        ),
         preceded(
             tag("backdrop-"),
             alt((
@@ -94,10 +99,6 @@ pub fn filter<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Filter
                         |b| Filter::BackdropGrayscale(BackdropGrayscale(b.0)),
                     ),
                 ),
-                map(
-                    |i| hue_rotate(i, config),
-                    |b| Filter::BackdropHueRotate(BackdropHueRotate(b.0)),
-                ),
                 preceded(
                     tag("invert"),
                     map(
@@ -129,7 +130,7 @@ pub fn filter<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Filter
 }
 
 impl<'a> IntoDeclaration for Filter<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Filter::Blur(f) => f.to_decl(),
             Filter::Brightness(f) => f.to_decl(),
@@ -159,11 +160,11 @@ impl<'a> IntoDeclaration for Filter<'a> {
 pub struct Blur<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for Blur<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-blur: blur({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-blur", format!("blur({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -173,11 +174,11 @@ impl<'a> IntoDeclaration for Blur<'a> {
 pub struct Brightness<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for Brightness<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-brightness: brightness({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-brightness", format!("brightness({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -187,11 +188,11 @@ impl<'a> IntoDeclaration for Brightness<'a> {
 pub struct Contrast<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for Contrast<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-contrast: contrast({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-contrast", format!("contrast({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -201,11 +202,11 @@ impl<'a> IntoDeclaration for Contrast<'a> {
 pub struct DropShadow<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for DropShadow<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-drop-shadow: drop-shadow({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-drop-shadow", format!("drop-shadow({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -215,11 +216,11 @@ impl<'a> IntoDeclaration for DropShadow<'a> {
 pub struct Grayscale<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for Grayscale<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-grayscale: grayscale({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-grayscale", format!("grayscale({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -234,11 +235,11 @@ fn hue_rotate<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, HueRot
 }
 
 impl IntoDeclaration for HueRotate {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-hue-rotate: hue-rotate({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-hue-rotate", format!("hue-rotate({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -248,11 +249,11 @@ impl IntoDeclaration for HueRotate {
 pub struct Invert<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for Invert<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-invert: invert({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-invert", format!("invert({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -262,11 +263,11 @@ impl<'a> IntoDeclaration for Invert<'a> {
 pub struct Saturate<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for Saturate<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-saturate: saturate({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-saturate", format!("saturate({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -276,11 +277,11 @@ impl<'a> IntoDeclaration for Saturate<'a> {
 pub struct Sepia<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for Sepia<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-sepia: sepia({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-sepia", format!("sepia({})", self.0)),
             FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -288,12 +289,12 @@ impl<'a> IntoDeclaration for Sepia<'a> {
 pub struct BackdropBlur<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for BackdropBlur<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-blur: blur({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-backdrop-blur", format!("blur({})", self.0)),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -301,12 +302,15 @@ impl<'a> IntoDeclaration for BackdropBlur<'a> {
 pub struct BackdropBrightness<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for BackdropBrightness<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-brightness: brightness({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new(
+                "--tw-backdrop-brightness",
+                format!("brightness({})", self.0),
+            ),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -314,12 +318,12 @@ impl<'a> IntoDeclaration for BackdropBrightness<'a> {
 pub struct BackdropContrast<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for BackdropContrast<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-contrast: contrast({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-backdrop-contrast", format!("contrast({})", self.0)),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -327,25 +331,28 @@ impl<'a> IntoDeclaration for BackdropContrast<'a> {
 pub struct BackdropGrayscale<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for BackdropGrayscale<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-grayscale: grayscale({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-backdrop-grayscale", format!("grayscale({})", self.0)),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
 #[derive(Debug, PartialEq, Hash)]
 pub struct BackdropHueRotate(pub String);
 
-impl<'a> IntoDeclaration for BackdropHueRotate {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-hue-rotate: hue-rotate({})", self.0),
+impl IntoDeclaration for BackdropHueRotate {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new(
+                "--tw-backdrop-hue-rotate",
+                format!("hue-rotate({})", self.0),
+            ),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -353,12 +360,12 @@ impl<'a> IntoDeclaration for BackdropHueRotate {
 pub struct BackdropInvert<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for BackdropInvert<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-invert: invert({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-backdrop-invert", format!("invert({})", self.0)),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -368,12 +375,12 @@ impl<'a> IntoDeclaration for BackdropInvert<'a> {
 pub struct BackdropOpacity<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for BackdropOpacity<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-opacity: opacity({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-backdrop-opacity", format!("opacity({})", self.0)),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -381,12 +388,12 @@ impl<'a> IntoDeclaration for BackdropOpacity<'a> {
 pub struct BackdropSaturate<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for BackdropSaturate<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-saturate: saturate({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-backdrop-saturate", format!("saturate({})", self.0)),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
 
@@ -394,11 +401,11 @@ impl<'a> IntoDeclaration for BackdropSaturate<'a> {
 pub struct BackdropSepia<'a>(pub &'a str);
 
 impl<'a> IntoDeclaration for BackdropSepia<'a> {
-    fn to_decl(self) -> Decl {
-        Decl::Vec(vec![
-            format!("--tw-backdrop-sepia: sepia({})", self.0),
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-backdrop-sepia", format!("sepia({})", self.0)),
             WEBKIT_BACKDROP_FILTER_STYLE.into(),
             BACKDROP_FILTER_STYLE.into(),
-        ])
+        ]
     }
 }
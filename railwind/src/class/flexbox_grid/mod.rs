@@ -1,14 +1,24 @@
 use macro_derive::{ConfigurableParser, EnumParser, IntoDeclaration};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::map;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::class::utils::{arbitrary, arbitrary_hashmap_value, neg_keyword_value};
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::Declaration;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum FlexboxGrid<'a> {
     Basis(Basis<'a>),
     Direction(Direction),
-    Wrap(Wrap),
+    Wrap(FlexWrap),
     Flex(Flex<'a>),
     Grow(Grow<'a>),
     Shrink(Shrink<'a>),
-    Order(Order<'a>),
+    Order(Order),
     GridTemplateColumns(GridTemplateColumns<'a>),
     GridColumn(GridColumn<'a>),
     GridTepmlateRows(GridTepmlateRows<'a>),
@@ -28,9 +38,139 @@ pub enum FlexboxGrid<'a> {
     PlaceSelf(PlaceSelf),
 }
 
-#[derive(Debug, PartialEq, Hash, ConfigurableParser)]
+pub fn flexbox_grid<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, FlexboxGrid<'a>> {
+    alt((
+        preceded(tag("basis"), map(|i| basis(i, config), FlexboxGrid::Basis)),
+        preceded(
+            tag("flex"),
+            alt((
+                preceded(
+                    tag("-"),
+                    alt((
+                        map(flex_direction, FlexboxGrid::Direction),
+                        map(flex_wrap, FlexboxGrid::Wrap),
+                    )),
+                ),
+                map(|i| flex(i, config), FlexboxGrid::Flex),
+            )),
+        ),
+        preceded(tag("grow"), map(|i| grow(i, config), FlexboxGrid::Grow)),
+        preceded(
+            tag("shrink"),
+            map(|i| shrink(i, config), FlexboxGrid::Shrink),
+        ),
+        map(
+            neg_keyword_value("order", config.flexbox_grid.get_order()),
+            |v| FlexboxGrid::Order(Order(v)),
+        ),
+        preceded(
+            tag("grid-"),
+            alt((
+                preceded(
+                    tag("cols"),
+                    map(
+                        |i| grid_template_columns(i, config),
+                        FlexboxGrid::GridTemplateColumns,
+                    ),
+                ),
+                preceded(
+                    tag("rows"),
+                    map(
+                        |i| grid_template_rows(i, config),
+                        FlexboxGrid::GridTepmlateRows,
+                    ),
+                ),
+                preceded(tag("flow-"), map(grid_auto_flow, FlexboxGrid::GridAutoFlow)),
+            )),
+        ),
+        preceded(
+            tag("col-"),
+            map(|i| grid_column(i, config), FlexboxGrid::GridColumn),
+        ),
+        preceded(
+            tag("row-"),
+            map(|i| grid_row(i, config), FlexboxGrid::GridRow),
+        ),
+        preceded(
+            tag("auto-"),
+            alt((
+                preceded(
+                    tag("cols"),
+                    map(
+                        |i| grid_auto_columns(i, config),
+                        FlexboxGrid::GridAutoColumns,
+                    ),
+                ),
+                preceded(
+                    tag("rows"),
+                    map(|i| grid_auto_rows(i, config), FlexboxGrid::GridAutoRows),
+                ),
+            )),
+        ),
+        preceded(tag("gap"), map(|i| gap(i, config), FlexboxGrid::Gap)),
+        preceded(
+            tag("justify-"),
+            alt((
+                preceded(tag("items-"), map(justify_items, FlexboxGrid::JustifyItems)),
+                preceded(tag("self-"), map(justify_self, FlexboxGrid::JustifySelf)),
+                map(justify_content, FlexboxGrid::JustifyContent),
+            )),
+        ),
+        preceded(
+            tag("content-"),
+            map(align_content, FlexboxGrid::AlignContent),
+        ),
+        preceded(tag("items-"), map(align_items, FlexboxGrid::AlignItems)),
+        preceded(tag("self-"), map(align_self, FlexboxGrid::AlignSelf)),
+        preceded(
+            tag("place-"),
+            alt((
+                preceded(
+                    tag("content-"),
+                    map(place_content, FlexboxGrid::PlaceContent),
+                ),
+                preceded(tag("items-"), map(place_items, FlexboxGrid::PlaceItems)),
+                preceded(tag("self-"), map(place_self, FlexboxGrid::PlaceSelf)),
+            )),
+        ),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for FlexboxGrid<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Basis(fg) => fg.to_decl(),
+            Self::Direction(fg) => fg.to_decl(),
+            Self::Wrap(fg) => fg.to_decl(),
+            Self::Flex(fg) => fg.to_decl(),
+            Self::Grow(fg) => fg.to_decl(),
+            Self::Shrink(fg) => fg.to_decl(),
+            Self::Order(fg) => fg.to_decl(),
+            Self::GridTemplateColumns(fg) => fg.to_decl(),
+            Self::GridColumn(fg) => fg.to_decl(),
+            Self::GridTepmlateRows(fg) => fg.to_decl(),
+            Self::GridRow(fg) => fg.to_decl(),
+            Self::GridAutoFlow(fg) => fg.to_decl(),
+            Self::GridAutoColumns(fg) => fg.to_decl(),
+            Self::GridAutoRows(fg) => fg.to_decl(),
+            Self::Gap(fg) => fg.to_decl(),
+            Self::JustifyContent(fg) => fg.to_decl(),
+            Self::JustifyItems(fg) => fg.to_decl(),
+            Self::JustifySelf(fg) => fg.to_decl(),
+            Self::AlignContent(fg) => fg.to_decl(),
+            Self::AlignItems(fg) => fg.to_decl(),
+            Self::AlignSelf(fg) => fg.to_decl(),
+            Self::PlaceContent(fg) => fg.to_decl(),
+            Self::PlaceItems(fg) => fg.to_decl(),
+            Self::PlaceSelf(fg) => fg.to_decl(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
 #[name(basis)]
 #[config(flexbox_grid.get_basis)]
+#[decl("flex-basis")]
 pub struct Basis<'a>(pub &'a str);
 
 #[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
@@ -55,7 +195,7 @@ pub enum Direction {
 #[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
 #[name(flex_wrap)]
 #[decl("flex-wrap")]
-pub enum Wrap {
+pub enum FlexWrap {
     #[tag("wrap")]
     Wrap,
 
@@ -66,16 +206,37 @@ pub enum Wrap {
     NoWrap,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
+#[name(flex)]
+#[config(flexbox_grid.get_flex)]
+#[decl("flex")]
 pub struct Flex<'a>(pub &'a str);
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
+#[name(grow)]
+#[config(flexbox_grid.get_grow)]
+#[decl("flex-grow")]
 pub struct Grow<'a>(pub &'a str);
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
+#[name(shrink)]
+#[config(flexbox_grid.get_shrink)]
+#[decl("flex-shrink")]
 pub struct Shrink<'a>(pub &'a str);
+
 #[derive(Debug, PartialEq, Hash)]
-pub struct Order<'a>(pub &'a str, bool);
-#[derive(Debug, PartialEq, Hash)]
+pub struct Order(pub String);
+
+impl IntoDeclaration for Order {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("order", self.0)]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
+#[name(grid_template_columns)]
+#[config(flexbox_grid.get_grid_template_columns)]
+#[decl("grid-template-columns")]
 pub struct GridTemplateColumns<'a>(pub &'a str);
 
 #[derive(Debug, PartialEq, Hash)]
@@ -84,11 +245,55 @@ pub enum GridColumn<'a> {
     Span(&'a str),
     Start(&'a str),
     End(&'a str),
+    Arbitrary(&'a str),
 }
-#[derive(Debug, PartialEq, Hash)]
+
+fn grid_column<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, GridColumn<'a>> {
+    alt((
+        map(tag("auto"), |_| GridColumn::Auto),
+        preceded(
+            tag("span-"),
+            map(
+                arbitrary_hashmap_value(config.flexbox_grid.get_grid_column_span()),
+                GridColumn::Span,
+            ),
+        ),
+        preceded(
+            tag("start-"),
+            map(
+                arbitrary_hashmap_value(config.flexbox_grid.get_grid_column_start()),
+                GridColumn::Start,
+            ),
+        ),
+        preceded(
+            tag("end-"),
+            map(
+                arbitrary_hashmap_value(config.flexbox_grid.get_grid_column_end()),
+                GridColumn::End,
+            ),
+        ),
+        map(arbitrary, GridColumn::Arbitrary),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for GridColumn<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Auto => vec![Declaration::new("grid-column", "auto")],
+            Self::Span(v) | Self::Arbitrary(v) => vec![Declaration::new("grid-column", v)],
+            Self::Start(v) => vec![Declaration::new("grid-column-start", v)],
+            Self::End(v) => vec![Declaration::new("grid-column-end", v)],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
+#[name(grid_template_rows)]
+#[config(flexbox_grid.get_grid_template_rows)]
+#[decl("grid-template-rows")]
 pub struct GridTepmlateRows<'a>(pub &'a str);
-#[derive(Debug, PartialEq, Hash)]
 
+#[derive(Debug, PartialEq, Hash)]
 pub enum GridRow<'a> {
     Auto,
     Span(&'a str),
@@ -97,231 +302,315 @@ pub enum GridRow<'a> {
     Arbitrary(&'a str),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+fn grid_row<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, GridRow<'a>> {
+    alt((
+        map(tag("auto"), |_| GridRow::Auto),
+        preceded(
+            tag("span-"),
+            map(
+                arbitrary_hashmap_value(config.flexbox_grid.get_grid_row_span()),
+                GridRow::Span,
+            ),
+        ),
+        preceded(
+            tag("start-"),
+            map(
+                arbitrary_hashmap_value(config.flexbox_grid.get_grid_row_start()),
+                GridRow::Start,
+            ),
+        ),
+        preceded(
+            tag("end-"),
+            map(
+                arbitrary_hashmap_value(config.flexbox_grid.get_grid_row_end()),
+                GridRow::End,
+            ),
+        ),
+        map(arbitrary, GridRow::Arbitrary),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for GridRow<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Auto => vec![Declaration::new("grid-row", "auto")],
+            Self::Span(v) | Self::Arbitrary(v) => vec![Declaration::new("grid-row", v)],
+            Self::Start(v) => vec![Declaration::new("grid-row-start", v)],
+            Self::End(v) => vec![Declaration::new("grid-row-end", v)],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(grid_auto_flow)]
+#[decl("grid-auto-flow")]
 pub enum GridAutoFlow {
+    #[tag("row")]
     Row,
+    #[tag("col")]
+    #[decl("column")]
     Col,
+    #[tag("dense")]
     Dense,
+    #[tag("row-dense")]
+    #[decl("row dense")]
     RowDense,
+    #[tag("col-dense")]
+    #[decl("column dense")]
     ColDense,
 }
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
+#[name(grid_auto_columns)]
+#[config(flexbox_grid.get_grid_auto_columns)]
+#[decl("grid-auto-columns")]
 pub struct GridAutoColumns<'a>(pub &'a str);
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, ConfigurableParser, IntoDeclaration)]
+#[name(grid_auto_rows)]
+#[config(flexbox_grid.get_grid_auto_rows)]
+#[decl("grid-auto-rows")]
 pub struct GridAutoRows<'a>(pub &'a str);
+
 #[derive(Debug, PartialEq, Hash)]
-pub struct Gap<'a>(pub &'a str);
-#[derive(Debug, PartialEq, Hash)]
+pub enum Gap<'a> {
+    All(&'a str),
+    X(&'a str),
+    Y(&'a str),
+}
+
+fn gap<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Gap<'a>> {
+    preceded(
+        tag("-"),
+        alt((
+            preceded(
+                tag("x-"),
+                map(
+                    arbitrary_hashmap_value(config.flexbox_grid.get_gap_x()),
+                    Gap::X,
+                ),
+            ),
+            preceded(
+                tag("y-"),
+                map(
+                    arbitrary_hashmap_value(config.flexbox_grid.get_gap_y()),
+                    Gap::Y,
+                ),
+            ),
+            map(
+                arbitrary_hashmap_value(config.flexbox_grid.get_gap()),
+                Gap::All,
+            ),
+        )),
+    )(input)
+}
+
+impl<'a> IntoDeclaration for Gap<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::All(v) => vec![Declaration::new("gap", v)],
+            Self::X(v) => vec![Declaration::new("column-gap", v)],
+            Self::Y(v) => vec![Declaration::new("row-gap", v)],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(justify_content)]
+#[decl("justify-content")]
 pub enum JustifyContent {
+    #[tag("start")]
+    #[decl("flex-start")]
     Start,
+    #[tag("end")]
+    #[decl("flex-end")]
     End,
+    #[tag("center")]
     Center,
+    #[tag("between")]
+    #[decl("space-between")]
     Between,
+    #[tag("around")]
+    #[decl("space-around")]
     Around,
+    #[tag("evenly")]
+    #[decl("space-evenly")]
     Evenly,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(justify_items)]
+#[decl("justify-items")]
 pub enum JustifyItems {
+    #[tag("start")]
     Start,
+    #[tag("end")]
     End,
+    #[tag("center")]
     Center,
+    #[tag("stretch")]
     Stretch,
 }
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(justify_self)]
+#[decl("justify-self")]
 pub enum JustifySelf {
+    #[tag("auto")]
     Auto,
+    #[tag("start")]
     Start,
+    #[tag("end")]
     End,
+    #[tag("center")]
     Center,
+    #[tag("stretch")]
     Stretch,
 }
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(align_content)]
+#[decl("align-content")]
 pub enum AlignContent {
+    #[tag("center")]
     Center,
+    #[tag("start")]
+    #[decl("flex-start")]
     Start,
+    #[tag("end")]
+    #[decl("flex-end")]
     End,
+    #[tag("between")]
+    #[decl("space-between")]
     Between,
+    #[tag("around")]
+    #[decl("space-around")]
     Around,
+    #[tag("evenly")]
+    #[decl("space-evenly")]
     Evenly,
+    #[tag("baseline")]
     Baseline,
 }
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(align_items)]
+#[decl("align-items")]
 pub enum AlignItems {
+    #[tag("start")]
+    #[decl("flex-start")]
     Start,
+    #[tag("end")]
+    #[decl("flex-end")]
     End,
+    #[tag("center")]
     Center,
+    #[tag("baseline")]
     Baseline,
+    #[tag("stretch")]
     Stretch,
 }
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(align_self)]
+#[decl("align-self")]
 pub enum AlignSelf {
+    #[tag("auto")]
     Auto,
+    #[tag("start")]
+    #[decl("flex-start")]
     Start,
+    #[tag("end")]
+    #[decl("flex-end")]
     End,
+    #[tag("center")]
     Center,
+    #[tag("stretch")]
     Stretch,
+    #[tag("baseline")]
     Baseline,
 }
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(place_content)]
+#[decl("place-content")]
 pub enum PlaceContent {
+    #[tag("center")]
     Center,
+    #[tag("start")]
     Start,
+    #[tag("end")]
     End,
+    #[tag("between")]
+    #[decl("space-between")]
     Between,
+    #[tag("around")]
+    #[decl("space-around")]
     Around,
+    #[tag("evenly")]
+    #[decl("space-evenly")]
     Evenly,
+    #[tag("baseline")]
     Baseline,
+    #[tag("stretch")]
     Stretch,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(place_items)]
+#[decl("place-items")]
 pub enum PlaceItems {
+    #[tag("start")]
     Start,
+    #[tag("end")]
     End,
+    #[tag("center")]
     Center,
+    #[tag("baseline")]
     Baseline,
+    #[tag("stretch")]
     Stretch,
 }
-#[derive(Debug, PartialEq, Hash)]
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(place_self)]
+#[decl("place-self")]
 pub enum PlaceSelf {
+    #[tag("auto")]
     Auto,
+    #[tag("start")]
     Start,
+    #[tag("end")]
     End,
+    #[tag("center")]
     Center,
+    #[tag("stretch")]
     Stretch,
 }
-// impl<'a> FlexboxGrid<'a> {
-//     pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-//         let flexbox_grid = match get_class_name(value) {
-//             "basis" => Self::Basis(Basis(get_args(value)?)),
-//             "flex" => {
-//                 let args = get_args(value)?;
-
-//                 if let Some(direction) = Direction::new(args) {
-//                     Self::Direction(direction)
-//                 } else if let Some(wrap) = Wrap::new(args) {
-//                     Self::Wrap(wrap)
-//                 } else {
-//                     Self::Flex(Flex(args))
-//                 }
-//             }
-//             "grow" => Self::Grow(Grow(get_opt_args(value))),
-//             "shrink" => Self::Shrink(Shrink(get_opt_args(value))),
-//             "order" | "-order" => Self::Order(Order::new(get_class_name(value), get_args(value)?)),
-//             "grid" => {
-//                 let args = get_args(value)?;
-
-//                 match get_class_name(args) {
-//                     "cols" => Self::GridTemplateColumns(GridTemplateColumns(get_args(args)?)),
-//                     "rows" => Self::GridTepmlateRows(GridTepmlateRows(get_args(args)?)),
-//                     "flow" => Self::GridAutoFlow(GridAutoFlow::new(get_args(args)?)?),
-//                     v => {
-//                         return Err(WarningType::InvalidArg(
-//                             v.into(),
-//                             "Grid Template / Auto Flow".into(),
-//                             vec!["cols", "rows", "flow"],
-//                         ))
-//                     }
-//                 }
-//             }
-//             "col" => Self::GridColumn(GridColumn::new(get_args(value)?)?),
-//             "row" => Self::GridRow(GridRow::new(get_args(value)?)),
-//             "auto" => {
-//                 let args = get_args(value)?;
-
-//                 match get_class_name(args) {
-//                     "cols" => Self::GridAutoColumns(GridAutoColumns(get_args(args)?)),
-//                     "rows" => Self::GridAutoRows(GridAutoRows(get_args(args)?)),
-//                     v => {
-//                         return Err(WarningType::InvalidArg(
-//                             v.into(),
-//                             "Grid Auto Columns / Rows".into(),
-//                             vec!["cols", "rows"],
-//                         ))
-//                     }
-//                 }
-//             }
-//             "gap" => Self::Gap(Gap(get_args(value)?)),
-//             "justify" => {
-//                 let args = get_args(value)?;
-
-//                 if let Some(content) = JustifyContent::new(args) {
-//                     Self::JustifyContent(content)
-//                 } else {
-//                     match get_class_name(args) {
-//                         "items" => Self::JustifyItems(JustifyItems::new(get_args(args)?)?),
-//                         "self" => Self::JustifySelf(JustifySelf::new(get_args(args)?)?),
-//                         v => {
-//                             return Err(WarningType::InvalidArg(
-//                                 v.into(),
-//                                 "Justify Items / Self".into(),
-//                                 vec![
-//                                     "start", "end", "center", "between", "around", "evenly",
-//                                     "items", "self",
-//                                 ],
-//                             ))
-//                         }
-//                     }
-//                 }
-//             }
-//             "content" => {
-//                 if let Some(align_content) = AlignContent::new(get_args(value)?) {
-//                     Self::AlignContent(align_content)
-//                 } else {
-//                     return Ok(None);
-//                 }
-//             }
-//             "items" => Self::AlignItems(AlignItems::new(get_args(value)?)?),
-//             "self" => Self::AlignSelf(AlignSelf::new(get_args(value)?)?),
-//             "place" => {
-//                 let args = get_args(value)?;
-
-//                 match get_class_name(args) {
-//                     "content" => Self::PlaceContent(PlaceContent::new(get_args(args)?)?),
-//                     "items" => Self::PlaceItems(PlaceItems::new(get_args(args)?)?),
-//                     "self" => Self::PlaceSelf(PlaceSelf::new(get_args(args)?)?),
-//                     v => {
-//                         return Err(WarningType::InvalidArg(
-//                             v.into(),
-//                             "Place Content / Items / Self".into(),
-//                             vec!["content", "items", "self"],
-//                         ))
-//                     }
-//                 }
-//             }
-//             _ => return Ok(None),
-//         };
-
-//         Ok(Some(flexbox_grid))
-//     }
-
-//     pub fn to_decl(self) -> Result<Decl, WarningType> {
-//         match self {
-//             Self::Basis(fg) => fg.to_decl(),
-//             Self::Direction(fg) => Ok(fg.to_decl()),
-//             Self::Wrap(fg) => Ok(fg.to_decl()),
-//             Self::Flex(fg) => fg.to_decl(),
-//             Self::Grow(fg) => fg.to_decl(),
-//             Self::Shrink(fg) => fg.to_decl(),
-//             Self::Order(fg) => fg.to_decl(),
-//             Self::GridTemplateColumns(fg) => fg.to_decl(),
-//             Self::GridColumn(fg) => fg.to_decl(),
-//             Self::GridTepmlateRows(fg) => fg.to_decl(),
-//             Self::GridRow(fg) => fg.to_decl(),
-//             Self::GridAutoFlow(fg) => Ok(fg.to_decl()),
-//             Self::GridAutoColumns(fg) => fg.to_decl(),
-//             Self::GridAutoRows(fg) => fg.to_decl(),
-//             Self::Gap(fg) => fg.to_decl(),
-//             Self::JustifyContent(fg) => Ok(fg.to_decl()),
-//             Self::JustifyItems(fg) => Ok(fg.to_decl()),
-//             Self::JustifySelf(fg) => Ok(fg.to_decl()),
-//             Self::AlignContent(fg) => Ok(fg.to_decl()),
-//             Self::AlignItems(fg) => Ok(fg.to_decl()),
-//             Self::AlignSelf(fg) => Ok(fg.to_decl()),
-//             Self::PlaceContent(fg) => Ok(fg.to_decl()),
-//             Self::PlaceItems(fg) => Ok(fg.to_decl()),
-//             Self::PlaceSelf(fg) => Ok(fg.to_decl()),
-//         }
-//     }
-// }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flexbox_grid() {
+        let config = Config::default();
+
+        assert_eq!(
+            flexbox_grid("flex-row", &config),
+            Ok(("", FlexboxGrid::Direction(Direction::Row)))
+        );
+        assert_eq!(
+            flexbox_grid("grow", &config),
+            Ok(("", FlexboxGrid::Grow(Grow("1"))))
+        );
+        assert_eq!(
+            flexbox_grid("-order-first", &config),
+            Ok(("", FlexboxGrid::Order(Order("9999".into()))))
+        );
+        assert_eq!(
+            flexbox_grid("gap-x-[2px]", &config),
+            Ok(("", FlexboxGrid::Gap(Gap::X("2px"))))
+        );
+    }
+}
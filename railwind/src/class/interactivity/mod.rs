@@ -1,24 +1,20 @@
-mod types;
+use macro_derive::{EnumParser, IntoDeclaration};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::map;
+use nom::sequence::preceded;
+use nom::IResult;
 
-use types::*;
+use crate::class::colors::{color, Color};
+use crate::class::utils::{arbitrary_hashmap_value, keyword_value, neg_keyword_value};
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::Declaration;
 
-use crate::class::Decl;
-use crate::utils::{get_args, get_class_name, get_opt_args};
-use crate::warning::WarningType;
-
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-lazy_static! {
-    pub static ref COLORS: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("../colors.ron")).unwrap();
-    pub static ref CURSOR: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("cursor.ron")).unwrap();
-    pub static ref MARGIN: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("margin.ron")).unwrap();
-    pub static ref PADDING: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("padding.ron")).unwrap();
-}
+const TOUCH_ACTION_STYLE: (&str, &str) = (
+    "touch-action",
+    "var(--tw-pan-x) var(--tw-pan-y) var(--tw-pinch-zoom)",
+);
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Interactivity<'a> {
@@ -29,7 +25,7 @@ pub enum Interactivity<'a> {
     PointerEvents(PointerEvents),
     Resize(Resize),
     ScrollBehavior(ScrollBehavior),
-    ScrollMargin(ScrollMargin<'a>),
+    ScrollMargin(ScrollMargin),
     ScrollPadding(ScrollPadding<'a>),
     ScrollSnapAlign(ScrollSnapAlign),
     ScrollSnapStop(ScrollSnapStop),
@@ -39,101 +35,457 @@ pub enum Interactivity<'a> {
     WillChange(WillChange),
 }
 
-impl<'a> Interactivity<'a> {
-    pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-        let class_name = get_class_name(value);
-
-        let interactivity = match class_name {
-            "accent" => Self::AccentColor(AccentColor(get_args(value)?)),
-            "appearance" => Self::Appearance(Appearance::new(get_args(value)?)?),
-            "cursor" => Self::Cursor(Cursor(get_args(value)?)),
-            "caret" => Self::CaretColor(CaretColor(get_args(value)?)),
-            "pointer" => {
-                let args = get_args(value)?;
-                match get_class_name(args) {
-                    "events" => Self::PointerEvents(PointerEvents::new(get_opt_args(args))?),
-                    v => {
-                        return Err(WarningType::InvalidArg(
-                            v.into(),
-                            "Pointer Events".into(),
-                            vec!["events"],
-                        ))
-                    }
-                }
-            }
-            "resize" => Self::Resize(Resize::new(value)?),
-            "scroll" | "-scroll" => {
-                if let Some(scroll) = ScrollBehavior::new(get_args(value)?) {
-                    Self::ScrollBehavior(scroll)
-                } else if let Some(scroll) = ScrollMargin::new(class_name, get_args(value)?) {
-                    Self::ScrollMargin(scroll)
-                } else if let Some(scroll) = ScrollPadding::new(class_name, get_args(value)?) {
-                    Self::ScrollPadding(scroll)
-                } else {
-                    return Err(WarningType::InvalidArg(
-                        value.into(),
-                        "Scroll Behavior / Margin / Padding".into(),
-                        vec![
-                            "auto", "smooth", "m", "mx", "my", "mt", "mr", "mb", "ml", "p", "px",
-                            "py", "pt", "pr", "pb", "pl",
-                        ],
-                    ));
-                }
-            }
-            "snap" => {
-                if let Some(snap) = ScrollSnapAlign::new(get_args(value)?) {
-                    Self::ScrollSnapAlign(snap)
-                } else if let Some(scroll) = ScrollSnapStop::new(get_args(value)?) {
-                    Self::ScrollSnapStop(scroll)
-                } else if let Some(scroll) = ScrollSnapType::new(get_args(value)?) {
-                    Self::ScrollSnapType(scroll)
-                } else {
-                    return Err(WarningType::InvalidArg(
-                        value.into(),
-                        "Scroll Snap Align / Stop / Type".into(),
-                        vec![
-                            "start",
-                            "end",
-                            "center",
-                            "align-none",
-                            "normal",
-                            "always",
-                            "none",
-                            "x",
-                            "y",
-                            "both",
-                            "mandatory",
-                            "proximity",
-                        ],
-                    ));
-                }
-            }
-            "touch" => Self::TouchAction(TouchAction::new(get_args(value)?)?),
-            "select" => Self::UserSelect(UserSelect::new(get_args(value)?)?),
-            "will" => Self::WillChange(WillChange::new(get_args(value)?)?),
-            _ => return Ok(None),
-        };
-
-        Ok(Some(interactivity))
-    }
+pub fn interactivity<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, Interactivity<'a>> {
+    alt((
+        preceded(
+            tag("accent-"),
+            map(|i| accent_color(i, config), Interactivity::AccentColor),
+        ),
+        preceded(
+            tag("appearance-"),
+            map(appearance, Interactivity::Appearance),
+        ),
+        preceded(
+            tag("cursor-"),
+            map(|i| cursor(i, config), Interactivity::Cursor),
+        ),
+        preceded(
+            tag("caret-"),
+            map(|i| caret_color(i, config), Interactivity::CaretColor),
+        ),
+        preceded(
+            tag("pointer-events-"),
+            map(pointer_events, Interactivity::PointerEvents),
+        ),
+        map(resize, Interactivity::Resize),
+        preceded(
+            tag("scroll-"),
+            map(scroll_behavior, Interactivity::ScrollBehavior),
+        ),
+        map(|i| scroll_margin(i, config), Interactivity::ScrollMargin),
+        map(|i| scroll_padding(i, config), Interactivity::ScrollPadding),
+        preceded(
+            tag("snap-"),
+            alt((
+                map(scroll_snap_align, Interactivity::ScrollSnapAlign),
+                map(scroll_snap_stop, Interactivity::ScrollSnapStop),
+                map(scroll_snap_type, Interactivity::ScrollSnapType),
+            )),
+        ),
+        preceded(tag("touch-"), map(touch_action, Interactivity::TouchAction)),
+        preceded(tag("select-"), map(user_select, Interactivity::UserSelect)),
+        preceded(
+            tag("will-change-"),
+            map(will_change, Interactivity::WillChange),
+        ),
+    ))(input)
+}
 
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
+impl<'a> IntoDeclaration for Interactivity<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Self::AccentColor(s) => s.to_decl(),
-            Self::Appearance(s) => Ok(s.to_decl()),
+            Self::Appearance(s) => s.to_decl(),
             Self::Cursor(s) => s.to_decl(),
             Self::CaretColor(s) => s.to_decl(),
-            Self::PointerEvents(s) => Ok(s.to_decl()),
-            Self::Resize(s) => Ok(s.to_decl()),
-            Self::ScrollBehavior(s) => Ok(s.to_decl()),
+            Self::PointerEvents(s) => s.to_decl(),
+            Self::Resize(s) => s.to_decl(),
+            Self::ScrollBehavior(s) => s.to_decl(),
             Self::ScrollMargin(s) => s.to_decl(),
             Self::ScrollPadding(s) => s.to_decl(),
-            Self::ScrollSnapAlign(s) => Ok(s.to_decl()),
-            Self::ScrollSnapStop(s) => Ok(s.to_decl()),
-            Self::ScrollSnapType(s) => Ok(s.to_decl()),
-            Self::TouchAction(s) => Ok(s.to_decl()),
-            Self::UserSelect(s) => Ok(s.to_decl()),
-            Self::WillChange(s) => Ok(s.to_decl()),
+            Self::ScrollSnapAlign(s) => s.to_decl(),
+            Self::ScrollSnapStop(s) => s.to_decl(),
+            Self::ScrollSnapType(s) => s.to_decl(),
+            Self::TouchAction(s) => s.to_decl(),
+            Self::UserSelect(s) => s.to_decl(),
+            Self::WillChange(s) => s.to_decl(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct AccentColor<'a>(pub Color<'a>);
+
+fn accent_color<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, AccentColor<'a>> {
+    map(color(config.interactivity.get_accent_color()), AccentColor)(input)
+}
+
+impl<'a> IntoDeclaration for AccentColor<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("accent-color", self.0.value(None))]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(appearance)]
+pub enum Appearance {
+    #[tag("none")]
+    None,
+}
+
+impl IntoDeclaration for Appearance {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::None => vec![
+                Declaration::new("-webkit-appearance", "none"),
+                Declaration::new("appearance", "none"),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("cursor")]
+pub struct Cursor<'a>(pub &'a str);
+
+fn cursor<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Cursor<'a>> {
+    map(
+        arbitrary_hashmap_value(config.interactivity.get_cursor()),
+        Cursor,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct CaretColor<'a>(pub Color<'a>);
+
+fn caret_color<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, CaretColor<'a>> {
+    map(color(config.interactivity.get_caret_color()), CaretColor)(input)
+}
+
+impl<'a> IntoDeclaration for CaretColor<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("caret-color", self.0.value(None))]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(pointer_events)]
+#[decl("pointer-events")]
+pub enum PointerEvents {
+    #[tag("none")]
+    None,
+    #[tag("auto")]
+    Auto,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(resize)]
+#[decl("resize")]
+pub enum Resize {
+    #[tag("resize-none")]
+    #[decl("none")]
+    None,
+    #[tag("resize-y")]
+    #[decl("vertical")]
+    Y,
+    #[tag("resize-x")]
+    #[decl("horizontal")]
+    X,
+    #[tag("resize")]
+    #[decl("both")]
+    Both,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(scroll_behavior)]
+#[decl("scroll-behavior")]
+pub enum ScrollBehavior {
+    #[tag("auto")]
+    Auto,
+    #[tag("smooth")]
+    Smooth,
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum ScrollMargin {
+    All(String),
+    X(String),
+    Y(String),
+    Top(String),
+    Right(String),
+    Bottom(String),
+    Left(String),
+}
+
+fn scroll_margin<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, ScrollMargin> {
+    let margin = config.interactivity.get_scroll_margin();
+
+    alt((
+        map(neg_keyword_value("scroll-m", margin), ScrollMargin::All),
+        map(neg_keyword_value("scroll-mx", margin), ScrollMargin::X),
+        map(neg_keyword_value("scroll-my", margin), ScrollMargin::Y),
+        map(neg_keyword_value("scroll-mt", margin), ScrollMargin::Top),
+        map(neg_keyword_value("scroll-mr", margin), ScrollMargin::Right),
+        map(neg_keyword_value("scroll-mb", margin), ScrollMargin::Bottom),
+        map(neg_keyword_value("scroll-ml", margin), ScrollMargin::Left),
+    ))(input)
+}
+
+impl IntoDeclaration for ScrollMargin {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::All(m) => vec![Declaration::new("scroll-margin", m)],
+            Self::X(m) => vec![
+                Declaration::new("scroll-margin-left", &m),
+                Declaration::new("scroll-margin-right", m),
+            ],
+            Self::Y(m) => vec![
+                Declaration::new("scroll-margin-top", &m),
+                Declaration::new("scroll-margin-bottom", m),
+            ],
+            Self::Top(m) => vec![Declaration::new("scroll-margin-top", m)],
+            Self::Right(m) => vec![Declaration::new("scroll-margin-right", m)],
+            Self::Bottom(m) => vec![Declaration::new("scroll-margin-bottom", m)],
+            Self::Left(m) => vec![Declaration::new("scroll-margin-left", m)],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum ScrollPadding<'a> {
+    All(&'a str),
+    X(&'a str),
+    Y(&'a str),
+    Top(&'a str),
+    Right(&'a str),
+    Bottom(&'a str),
+    Left(&'a str),
+}
+
+fn scroll_padding<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, ScrollPadding<'a>> {
+    let padding = config.interactivity.get_scroll_padding();
+
+    alt((
+        map(keyword_value("scroll-p", padding), ScrollPadding::All),
+        map(keyword_value("scroll-px", padding), ScrollPadding::X),
+        map(keyword_value("scroll-py", padding), ScrollPadding::Y),
+        map(keyword_value("scroll-pt", padding), ScrollPadding::Top),
+        map(keyword_value("scroll-pr", padding), ScrollPadding::Right),
+        map(keyword_value("scroll-pb", padding), ScrollPadding::Bottom),
+        map(keyword_value("scroll-pl", padding), ScrollPadding::Left),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for ScrollPadding<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::All(p) => vec![Declaration::new("scroll-padding", p)],
+            Self::X(p) => vec![
+                Declaration::new("scroll-padding-left", p),
+                Declaration::new("scroll-padding-right", p),
+            ],
+            Self::Y(p) => vec![
+                Declaration::new("scroll-padding-top", p),
+                Declaration::new("scroll-padding-bottom", p),
+            ],
+            Self::Top(p) => vec![Declaration::new("scroll-padding-top", p)],
+            Self::Right(p) => vec![Declaration::new("scroll-padding-right", p)],
+            Self::Bottom(p) => vec![Declaration::new("scroll-padding-bottom", p)],
+            Self::Left(p) => vec![Declaration::new("scroll-padding-left", p)],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(scroll_snap_align)]
+#[decl("scroll-snap-align")]
+pub enum ScrollSnapAlign {
+    #[tag("start")]
+    Start,
+    #[tag("end")]
+    End,
+    #[tag("center")]
+    Center,
+    #[tag("align-none")]
+    #[decl("none")]
+    None,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(scroll_snap_stop)]
+#[decl("scroll-snap-stop")]
+pub enum ScrollSnapStop {
+    #[tag("normal")]
+    Normal,
+    #[tag("always")]
+    Always,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(scroll_snap_type)]
+pub enum ScrollSnapType {
+    #[tag("none")]
+    None,
+    #[tag("x")]
+    X,
+    #[tag("y")]
+    Y,
+    #[tag("both")]
+    Both,
+    #[tag("mandatory")]
+    Mandatory,
+    #[tag("proximity")]
+    Proximity,
+}
+
+impl IntoDeclaration for ScrollSnapType {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::None => vec![Declaration::new("scroll-snap-type", "none")],
+            Self::X => vec![Declaration::new(
+                "scroll-snap-type",
+                "x var(--tw-scroll-snap-strictness)",
+            )],
+            Self::Y => vec![Declaration::new(
+                "scroll-snap-type",
+                "y var(--tw-scroll-snap-strictness)",
+            )],
+            Self::Both => vec![Declaration::new(
+                "scroll-snap-type",
+                "both var(--tw-scroll-snap-strictness)",
+            )],
+            Self::Mandatory => vec![Declaration::new("--tw-scroll-snap-strictness", "mandatory")],
+            Self::Proximity => vec![Declaration::new("--tw-scroll-snap-strictness", "proximity")],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(touch_action)]
+pub enum TouchAction {
+    #[tag("auto")]
+    Auto,
+    #[tag("none")]
+    None,
+    #[tag("pan-x")]
+    PanX,
+    #[tag("pan-left")]
+    PanLeft,
+    #[tag("pan-right")]
+    PanRight,
+    #[tag("pan-y")]
+    PanY,
+    #[tag("pan-up")]
+    PanUp,
+    #[tag("pan-down")]
+    PanDown,
+    #[tag("pinch-zoom")]
+    PinchZoom,
+    #[tag("manipulation")]
+    Manipulation,
+}
+
+impl IntoDeclaration for TouchAction {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Auto => vec![Declaration::new("touch-action", "auto")],
+            Self::None => vec![Declaration::new("touch-action", "none")],
+            Self::PanX => vec![
+                Declaration::new("--tw-pan-x", "pan-x"),
+                TOUCH_ACTION_STYLE.into(),
+            ],
+            Self::PanLeft => {
+                vec![
+                    Declaration::new("--tw-pan-x", "pan-left"),
+                    TOUCH_ACTION_STYLE.into(),
+                ]
+            }
+            Self::PanRight => {
+                vec![
+                    Declaration::new("--tw-pan-x", "pan-right"),
+                    TOUCH_ACTION_STYLE.into(),
+                ]
+            }
+            Self::PanY => vec![
+                Declaration::new("--tw-pan-y", "pan-y"),
+                TOUCH_ACTION_STYLE.into(),
+            ],
+            Self::PanUp => vec![
+                Declaration::new("--tw-pan-y", "pan-up"),
+                TOUCH_ACTION_STYLE.into(),
+            ],
+            Self::PanDown => {
+                vec![
+                    Declaration::new("--tw-pan-y", "pan-down"),
+                    TOUCH_ACTION_STYLE.into(),
+                ]
+            }
+            Self::PinchZoom => vec![
+                Declaration::new("--tw-pinch-zoom", "pinch-zoom"),
+                TOUCH_ACTION_STYLE.into(),
+            ],
+            Self::Manipulation => vec![Declaration::new("touch-action", "manipulation")],
         }
     }
 }
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(user_select)]
+pub enum UserSelect {
+    #[tag("none")]
+    None,
+    #[tag("text")]
+    Text,
+    #[tag("all")]
+    All,
+    #[tag("auto")]
+    Auto,
+}
+
+impl IntoDeclaration for UserSelect {
+    fn to_decl(self) -> Vec<Declaration> {
+        let value = match self {
+            Self::None => "none",
+            Self::Text => "text",
+            Self::All => "all",
+            Self::Auto => "auto",
+        };
+
+        vec![
+            Declaration::new("-webkit-user-select", value),
+            Declaration::new("user-select", value),
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(will_change)]
+#[decl("will-change")]
+pub enum WillChange {
+    #[tag("auto")]
+    Auto,
+    #[tag("scroll")]
+    #[decl("scroll-position")]
+    Scroll,
+    #[tag("contents")]
+    Contents,
+    #[tag("transform")]
+    Transform,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll() {
+        assert_eq!(
+            interactivity("scroll-smooth", &Config::default()),
+            Ok(("", Interactivity::ScrollBehavior(ScrollBehavior::Smooth)))
+        );
+        assert_eq!(
+            interactivity("-scroll-mx-4", &Config::default()),
+            Ok((
+                "",
+                Interactivity::ScrollMargin(ScrollMargin::X("-1rem".into()))
+            ))
+        );
+        assert_eq!(
+            interactivity("scroll-p-[10px]", &Config::default()),
+            Ok(("", Interactivity::ScrollPadding(ScrollPadding::All("10px"))))
+        );
+    }
+}
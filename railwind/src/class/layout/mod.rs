@@ -1,39 +1,21 @@
-mod types;
-
-pub use types::*;
-
-use crate::class::Decl;
-use crate::utils::{get_args, get_class_name, get_opt_args};
-use crate::warning::WarningType;
-
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-lazy_static! {
-    pub static ref ASPECT_RATIO: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("aspect_ratio.ron")).unwrap();
-    pub static ref COLUMNS: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("columns.ron")).unwrap();
-    pub static ref OBJECT_POSITION: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("object_position.ron")).unwrap();
-    pub static ref INSET: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("top_right_bottom_left.ron")).unwrap();
-    pub static ref TOP: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("top_right_bottom_left.ron")).unwrap();
-    pub static ref RIGHT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("top_right_bottom_left.ron")).unwrap();
-    pub static ref BOTTOM: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("top_right_bottom_left.ron")).unwrap();
-    pub static ref LEFT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("top_right_bottom_left.ron")).unwrap();
-    pub static ref Z_INDEX: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("z_index.ron")).unwrap();
-}
+use macro_derive::{EnumParser, IntoDeclaration};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::{all_consuming, eof, map, rest, verify};
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::class::utils::{arbitrary_hashmap_value, neg_keyword_value};
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::{AtRule, Declaration, Node, Rule, Selector};
+use crate::modifiers::{Screen, Screens};
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Layout<'a> {
     AspectRatio(AspectRatio<'a>),
     Container(Container),
+    ContainerType(ContainerType<'a>),
     Columns(Columns<'a>),
     BreakAfter(BreakAfter),
     BreakBefore(BreakBefore),
@@ -49,91 +31,681 @@ pub enum Layout<'a> {
     Overflow(Overflow),
     Overscroll(Overscroll),
     Position(Position),
-    TopRightBottomLeft(TopRightBottomLeft<'a>),
+    TopRightBottomLeft(TopRightBottomLeft),
     Visibility(Visibility),
-    ZIndex(ZIndex<'a>),
-}
-
-impl<'a> Layout<'a> {
-    pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-        let layout = match get_class_name(value) {
-            "aspect" => Self::AspectRatio(AspectRatio(get_args(value)?)),
-            "container" => Self::Container(Container),
-            "columns" => Self::Columns(Columns(get_args(value)?)),
-            "break" => {
-                let args = get_args(value)?;
-
-                match get_class_name(args) {
-                    "after" => Self::BreakAfter(BreakAfter::new(get_args(args)?)?),
-                    "before" => Self::BreakBefore(BreakBefore::new(get_args(args)?)?),
-                    "inside" => Self::BreakInside(BreakInside::new(get_args(args)?)?),
-                    _ => return Ok(None),
-                }
-            }
-
-            "box" => {
-                let args = get_args(value)?;
-                match get_class_name(args) {
-                    "decoration" => Self::BoxDecoration(BoxDecoration::new(get_args(args)?)?),
-                    _ => Self::BoxSizing(BoxSizing::new(args)?),
-                }
-            }
-            "float" => Self::Floats(Floats::new(get_args(value)?)?),
-            "clear" => Self::Clear(Clear::new(get_args(value)?)?),
-            "object" => {
-                if let Some(object_fit) = ObjectFit::new(get_args(value)?) {
-                    Self::ObjectFit(object_fit)
-                } else {
-                    Self::ObjectPosition(ObjectPosition(get_args(value)?))
-                }
-            }
-            "overflow" => Self::Overflow(Overflow::new(get_args(value)?)?),
-            "overscroll" => Self::Overscroll(Overscroll::new(get_args(value)?)?),
-            "z" | "-z" => Self::ZIndex(ZIndex::new(get_class_name(value), get_args(value)?)),
-            _ => {
-                if let Some(display) = Display::new(value) {
-                    Self::Display(display)
-                } else if let Some(isolation) = Isolation::new(value) {
-                    Self::Isolation(isolation)
-                } else if let Some(position) = Position::new(value) {
-                    Self::Position(position)
-                } else if let Some(top_right_bottom_left) =
-                    TopRightBottomLeft::new(get_class_name(value), get_opt_args(value))
-                {
-                    Self::TopRightBottomLeft(top_right_bottom_left)
-                } else if let Some(visibility) = Visibility::new(value) {
-                    Self::Visibility(visibility)
-                } else {
-                    return Ok(None);
-                }
-            }
-        };
+    ZIndex(ZIndex),
+}
 
-        Ok(Some(layout))
-    }
+pub fn layout<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Layout<'a>> {
+    alt((
+        preceded(
+            tag("aspect-"),
+            map(|i| aspect_ratio(i, config), Layout::AspectRatio),
+        ),
+        map(all_consuming(tag("container")), |_| {
+            Layout::Container(Container)
+        }),
+        preceded(
+            tag("@container"),
+            map(container_type, Layout::ContainerType),
+        ),
+        preceded(
+            tag("columns-"),
+            map(|i| columns(i, config), Layout::Columns),
+        ),
+        preceded(
+            tag("break-"),
+            alt((
+                preceded(tag("after-"), map(break_after, Layout::BreakAfter)),
+                preceded(tag("before-"), map(break_before, Layout::BreakBefore)),
+                preceded(tag("inside-"), map(break_inside, Layout::BreakInside)),
+            )),
+        ),
+        preceded(
+            tag("box-"),
+            alt((
+                preceded(
+                    tag("decoration-"),
+                    map(box_decoration, Layout::BoxDecoration),
+                ),
+                map(box_sizing, Layout::BoxSizing),
+            )),
+        ),
+        map(display, Layout::Display),
+        preceded(tag("float-"), map(floats, Layout::Floats)),
+        preceded(tag("clear-"), map(clear, Layout::Clear)),
+        map(isolation, Layout::Isolation),
+        preceded(
+            tag("object-"),
+            alt((
+                map(object_fit, Layout::ObjectFit),
+                map(|i| object_position(i, config), Layout::ObjectPosition),
+            )),
+        ),
+        preceded(tag("overflow-"), map(overflow, Layout::Overflow)),
+        preceded(tag("overscroll-"), map(overscroll, Layout::Overscroll)),
+        map(position, Layout::Position),
+        map(
+            |i| top_right_bottom_left(i, config),
+            Layout::TopRightBottomLeft,
+        ),
+        map(visibility, Layout::Visibility),
+        map(|i| z_index(i, config), Layout::ZIndex),
+    ))(input)
+}
 
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
+impl<'a> IntoDeclaration for Layout<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Self::AspectRatio(l) => l.to_decl(),
-            Self::Container(l) => Ok(l.to_decl()),
+            Self::Container(l) => l.to_decl(),
+            Self::ContainerType(l) => l.to_decl(),
             Self::Columns(l) => l.to_decl(),
-            Self::BreakAfter(l) => Ok(l.to_decl()),
-            Self::BreakBefore(l) => Ok(l.to_decl()),
-            Self::BreakInside(l) => Ok(l.to_decl()),
-            Self::BoxDecoration(l) => Ok(l.to_decl()),
-            Self::BoxSizing(l) => Ok(l.to_decl()),
-            Self::Display(l) => Ok(l.to_decl()),
-            Self::Floats(l) => Ok(l.to_decl()),
-            Self::Clear(l) => Ok(l.to_decl()),
-            Self::Isolation(l) => Ok(l.to_decl()),
-            Self::ObjectFit(l) => Ok(l.to_decl()),
+            Self::BreakAfter(l) => l.to_decl(),
+            Self::BreakBefore(l) => l.to_decl(),
+            Self::BreakInside(l) => l.to_decl(),
+            Self::BoxDecoration(l) => l.to_decl(),
+            Self::BoxSizing(l) => l.to_decl(),
+            Self::Display(l) => l.to_decl(),
+            Self::Floats(l) => l.to_decl(),
+            Self::Clear(l) => l.to_decl(),
+            Self::Isolation(l) => l.to_decl(),
+            Self::ObjectFit(l) => l.to_decl(),
             Self::ObjectPosition(l) => l.to_decl(),
-            Self::Overflow(l) => Ok(l.to_decl()),
-            Self::Overscroll(l) => Ok(l.to_decl()),
-            Self::Position(l) => Ok(l.to_decl()),
+            Self::Overflow(l) => l.to_decl(),
+            Self::Overscroll(l) => l.to_decl(),
+            Self::Position(l) => l.to_decl(),
             Self::TopRightBottomLeft(l) => l.to_decl(),
-            Self::Visibility(l) => Ok(l.to_decl()),
+            Self::Visibility(l) => l.to_decl(),
             Self::ZIndex(l) => l.to_decl(),
         }
     }
 }
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("aspect-ratio")]
+pub struct AspectRatio<'a>(pub &'a str);
+
+fn aspect_ratio<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, AspectRatio<'a>> {
+    map(
+        arbitrary_hashmap_value(config.layout.get_aspect_ratio()),
+        AspectRatio,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct Container;
+
+impl Container {
+    /// The `max-width` rules for every screen with a minimum width,
+    /// which follow the rule with the `width`
+    pub fn breakpoint_nodes(selector: &Selector, screens: &Screens) -> Vec<Node> {
+        screens
+            .values()
+            .filter_map(Screen::min_width)
+            .map(|min_width| {
+                AtRule::media(
+                    format!("(min-width: {})", min_width),
+                    vec![Rule::new(
+                        selector.clone(),
+                        vec![Declaration::new("max-width", min_width)],
+                    )
+                    .into()],
+                )
+                .into()
+            })
+            .collect()
+    }
+}
+
+impl IntoDeclaration for Container {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("width", "100%")]
+    }
+}
+
+/// Makes the element a container for the `@md:` variants of its children.
+/// A name after a slash, like `@container/sidebar`, is what `@md/sidebar:` looks for.
+#[derive(Debug, PartialEq, Hash)]
+pub enum ContainerType<'a> {
+    InlineSize(Option<&'a str>),
+    Normal(Option<&'a str>),
+}
+
+fn container_type(input: &str) -> IResult<&str, ContainerType<'_>> {
+    alt((
+        map(
+            preceded(tag("-normal"), container_name),
+            ContainerType::Normal,
+        ),
+        map(container_name, ContainerType::InlineSize),
+    ))(input)
+}
+
+fn container_name(input: &str) -> IResult<&str, Option<&str>> {
+    alt((
+        map(eof, |_| None),
+        preceded(tag("/"), map(verify(rest, |n: &str| !n.is_empty()), Some)),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for ContainerType<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        let (container_type, name) = match self {
+            Self::InlineSize(name) => ("inline-size", name),
+            Self::Normal(name) => ("normal", name),
+        };
+
+        let mut decls = vec![Declaration::new("container-type", container_type)];
+        if let Some(name) = name {
+            decls.push(Declaration::new("container-name", name));
+        }
+        decls
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("columns")]
+pub struct Columns<'a>(pub &'a str);
+
+fn columns<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Columns<'a>> {
+    map(
+        arbitrary_hashmap_value(config.layout.get_columns()),
+        Columns,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(break_after)]
+#[decl("break-after")]
+pub enum BreakAfter {
+    #[tag("auto")]
+    Auto,
+    #[tag("avoid")]
+    Avoid,
+    #[tag("all")]
+    All,
+    #[tag("avoid-page")]
+    AvoidPage,
+    #[tag("page")]
+    Page,
+    #[tag("left")]
+    Left,
+    #[tag("right")]
+    Right,
+    #[tag("column")]
+    Column,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(break_before)]
+#[decl("break-before")]
+pub enum BreakBefore {
+    #[tag("auto")]
+    Auto,
+    #[tag("avoid")]
+    Avoid,
+    #[tag("all")]
+    All,
+    #[tag("avoid-page")]
+    AvoidPage,
+    #[tag("page")]
+    Page,
+    #[tag("left")]
+    Left,
+    #[tag("right")]
+    Right,
+    #[tag("column")]
+    Column,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(break_inside)]
+#[decl("break-inside")]
+pub enum BreakInside {
+    #[tag("auto")]
+    Auto,
+    #[tag("avoid")]
+    Avoid,
+    #[tag("avoid-page")]
+    AvoidPage,
+    #[tag("avoid-column")]
+    AvoidColumn,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(box_decoration)]
+pub enum BoxDecoration {
+    #[tag("clone")]
+    Clone,
+    #[tag("slice")]
+    Slice,
+}
+
+impl IntoDeclaration for BoxDecoration {
+    fn to_decl(self) -> Vec<Declaration> {
+        let val = match self {
+            Self::Clone => "clone",
+            Self::Slice => "slice",
+        };
+
+        vec![
+            Declaration::new("-webkit-box-decoration-break", val),
+            Declaration::new("box-decoration-break", val),
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(box_sizing)]
+#[decl("box-sizing")]
+pub enum BoxSizing {
+    #[tag("border")]
+    #[decl("border-box")]
+    Border,
+    #[tag("content")]
+    #[decl("content-box")]
+    Content,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(display)]
+#[decl("display")]
+pub enum Display {
+    #[tag("block")]
+    Block,
+    #[tag("inline-block")]
+    InlineBlock,
+    #[tag("inline")]
+    Inline,
+    #[tag("flex")]
+    Flex,
+    #[tag("inline-flex")]
+    InlineFlex,
+    #[tag("table")]
+    Table,
+    #[tag("inline-table")]
+    InlineTable,
+    #[tag("table-caption")]
+    TableCaption,
+    #[tag("table-cell")]
+    TableCell,
+    #[tag("table-column")]
+    TableColumn,
+    #[tag("table-column-group")]
+    TableColumnGroup,
+    #[tag("table-footer-group")]
+    TableFooterGroup,
+    #[tag("table-header-group")]
+    TableHeaderGroup,
+    #[tag("table-row-group")]
+    TableRowGroup,
+    #[tag("table-row")]
+    TableRow,
+    #[tag("flow-root")]
+    FlowRoot,
+    #[tag("grid")]
+    Grid,
+    #[tag("inline-grid")]
+    InlineGrid,
+    #[tag("contents")]
+    Contents,
+    #[tag("list-item")]
+    ListItem,
+    #[tag("hidden")]
+    #[decl("none")]
+    Hidden,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(floats)]
+#[decl("float")]
+pub enum Floats {
+    #[tag("right")]
+    Right,
+    #[tag("left")]
+    Left,
+    #[tag("none")]
+    None,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(clear)]
+#[decl("clear")]
+pub enum Clear {
+    #[tag("left")]
+    Left,
+    #[tag("right")]
+    Right,
+    #[tag("both")]
+    Both,
+    #[tag("none")]
+    None,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(isolation)]
+#[decl("isolation")]
+pub enum Isolation {
+    #[tag("isolate")]
+    Isolate,
+    #[tag("isolation-auto")]
+    #[decl("auto")]
+    IsolationAuto,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(object_fit)]
+#[decl("object-fit")]
+pub enum ObjectFit {
+    #[tag("contain")]
+    Contain,
+    #[tag("cover")]
+    Cover,
+    #[tag("fill")]
+    Fill,
+    #[tag("none")]
+    None,
+    #[tag("scale-down")]
+    ScaleDown,
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("object-position")]
+pub struct ObjectPosition<'a>(pub &'a str);
+
+fn object_position<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, ObjectPosition<'a>> {
+    map(
+        arbitrary_hashmap_value(config.layout.get_object_position()),
+        ObjectPosition,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(overflow)]
+pub enum Overflow {
+    #[tag("auto")]
+    Auto,
+    #[tag("hidden")]
+    Hidden,
+    #[tag("clip")]
+    Clip,
+    #[tag("visible")]
+    Visible,
+    #[tag("scroll")]
+    Scroll,
+    #[tag("x-auto")]
+    XAuto,
+    #[tag("y-auto")]
+    YAuto,
+    #[tag("x-hidden")]
+    XHidden,
+    #[tag("y-hidden")]
+    YHidden,
+    #[tag("x-clip")]
+    XClip,
+    #[tag("y-clip")]
+    YClip,
+    #[tag("x-visible")]
+    XVisible,
+    #[tag("y-visible")]
+    YVisible,
+    #[tag("x-scroll")]
+    XScroll,
+    #[tag("y-scroll")]
+    YScroll,
+}
+
+impl IntoDeclaration for Overflow {
+    fn to_decl(self) -> Vec<Declaration> {
+        let (suffix, val) = match self {
+            Self::Auto => ("", "auto"),
+            Self::Hidden => ("", "hidden"),
+            Self::Clip => ("", "clip"),
+            Self::Visible => ("", "visible"),
+            Self::Scroll => ("", "scroll"),
+            Self::XAuto => ("-x", "auto"),
+            Self::YAuto => ("-y", "auto"),
+            Self::XHidden => ("-x", "hidden"),
+            Self::YHidden => ("-y", "hidden"),
+            Self::XClip => ("-x", "clip"),
+            Self::YClip => ("-y", "clip"),
+            Self::XVisible => ("-x", "visible"),
+            Self::YVisible => ("-y", "visible"),
+            Self::XScroll => ("-x", "scroll"),
+            Self::YScroll => ("-y", "scroll"),
+        };
+
+        vec![Declaration::new(format!("overflow{}", suffix), val)]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(overscroll)]
+pub enum Overscroll {
+    #[tag("auto")]
+    Auto,
+    #[tag("contain")]
+    Contain,
+    #[tag("none")]
+    None,
+    #[tag("y-auto")]
+    YAuto,
+    #[tag("y-contain")]
+    YContain,
+    #[tag("y-none")]
+    YNone,
+    #[tag("x-auto")]
+    XAuto,
+    #[tag("x-contain")]
+    XContain,
+    #[tag("x-none")]
+    XNone,
+}
+
+impl IntoDeclaration for Overscroll {
+    fn to_decl(self) -> Vec<Declaration> {
+        let (suffix, val) = match self {
+            Self::Auto => ("", "auto"),
+            Self::Contain => ("", "contain"),
+            Self::None => ("", "none"),
+            Self::YAuto => ("-y", "auto"),
+            Self::YContain => ("-y", "contain"),
+            Self::YNone => ("-y", "none"),
+            Self::XAuto => ("-x", "auto"),
+            Self::XContain => ("-x", "contain"),
+            Self::XNone => ("-x", "none"),
+        };
+
+        vec![Declaration::new(
+            format!("overscroll-behavior{}", suffix),
+            val,
+        )]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(position)]
+#[decl("position")]
+pub enum Position {
+    #[tag("static")]
+    Static,
+    #[tag("fixed")]
+    Fixed,
+    #[tag("absolute")]
+    Absolute,
+    #[tag("relative")]
+    Relative,
+    #[tag("sticky")]
+    Sticky,
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum TopRightBottomLeft {
+    Inset(String),
+    InsetX(String),
+    InsetY(String),
+    Top(String),
+    Right(String),
+    Bottom(String),
+    Left(String),
+}
+
+fn top_right_bottom_left<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, TopRightBottomLeft> {
+    let inset = config.layout.get_inset();
+
+    alt((
+        map(
+            neg_keyword_value("inset-x", inset),
+            TopRightBottomLeft::InsetX,
+        ),
+        map(
+            neg_keyword_value("inset-y", inset),
+            TopRightBottomLeft::InsetY,
+        ),
+        map(neg_keyword_value("inset", inset), TopRightBottomLeft::Inset),
+        map(neg_keyword_value("top", inset), TopRightBottomLeft::Top),
+        map(neg_keyword_value("right", inset), TopRightBottomLeft::Right),
+        map(
+            neg_keyword_value("bottom", inset),
+            TopRightBottomLeft::Bottom,
+        ),
+        map(neg_keyword_value("left", inset), TopRightBottomLeft::Left),
+    ))(input)
+}
+
+impl IntoDeclaration for TopRightBottomLeft {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Inset(v) => vec![
+                Declaration::new("top", &v),
+                Declaration::new("right", &v),
+                Declaration::new("bottom", &v),
+                Declaration::new("left", v),
+            ],
+            Self::InsetX(v) => vec![Declaration::new("left", &v), Declaration::new("right", v)],
+            Self::InsetY(v) => vec![Declaration::new("top", &v), Declaration::new("bottom", v)],
+            Self::Top(v) => vec![Declaration::new("top", v)],
+            Self::Right(v) => vec![Declaration::new("right", v)],
+            Self::Bottom(v) => vec![Declaration::new("bottom", v)],
+            Self::Left(v) => vec![Declaration::new("left", v)],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(visibility)]
+#[decl("visibility")]
+pub enum Visibility {
+    #[tag("visible")]
+    Visible,
+    #[tag("invisible")]
+    #[decl("hidden")]
+    Invisible,
+    #[tag("collapse")]
+    Collapse,
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct ZIndex(pub String);
+
+fn z_index<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, ZIndex> {
+    map(neg_keyword_value("z", config.layout.get_z_index()), ZIndex)(input)
+}
+
+impl IntoDeclaration for ZIndex {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("z-index", self.0)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            layout("hidden", &Config::default()),
+            Ok(("", Layout::Display(Display::Hidden)))
+        );
+        assert_eq!(
+            layout("inline-flex", &Config::default()),
+            Ok(("", Layout::Display(Display::InlineFlex)))
+        );
+    }
+
+    #[test]
+    fn test_top_right_bottom_left() {
+        assert_eq!(
+            layout("-inset-x-4", &Config::default()),
+            Ok((
+                "",
+                Layout::TopRightBottomLeft(TopRightBottomLeft::InsetX("-1rem".into()))
+            ))
+        );
+        assert_eq!(
+            layout("left-[54]", &Config::default()),
+            Ok((
+                "",
+                Layout::TopRightBottomLeft(TopRightBottomLeft::Left("54".into()))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_container_type() {
+        assert_eq!(
+            layout("@container", &Config::default()),
+            Ok(("", Layout::ContainerType(ContainerType::InlineSize(None))))
+        );
+        assert_eq!(
+            layout("@container-normal/sidebar", &Config::default()),
+            Ok((
+                "",
+                Layout::ContainerType(ContainerType::Normal(Some("sidebar")))
+            ))
+        );
+        assert!(layout("@container/", &Config::default()).is_err());
+    }
+
+    #[test]
+    fn test_container_breakpoints() {
+        let screens = Screens::from([
+            ("sm".to_string(), Screen::Max("639px".into())),
+            (
+                "md".to_string(),
+                Screen::Range {
+                    min: "640px".into(),
+                    max: "1023px".into(),
+                },
+            ),
+            (
+                "tall".to_string(),
+                Screen::Raw("(min-height: 800px)".into()),
+            ),
+            ("lg".to_string(), Screen::Min("1024px".into())),
+        ]);
+
+        let selector = Selector::new(".container");
+        let breakpoint = |min_width: &str| -> Node {
+            AtRule::media(
+                format!("(min-width: {})", min_width),
+                vec![Rule::new(
+                    selector.clone(),
+                    vec![Declaration::new("max-width", min_width)],
+                )
+                .into()],
+            )
+            .into()
+        };
+
+        assert_eq!(
+            Container::breakpoint_nodes(&selector, &screens),
+            vec![breakpoint("640px"), breakpoint("1024px")]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod accessibility;
+mod arbitrary_property;
 mod backgrounds;
 mod borders;
 mod colors;
@@ -7,6 +8,7 @@ mod filters;
 mod flexbox_grid;
 mod interactivity;
 mod layout;
+mod plugin;
 mod sizing;
 mod spacing;
 mod svg;
@@ -17,6 +19,7 @@ mod typography;
 mod utils;
 
 pub use accessibility::*;
+pub use arbitrary_property::*;
 pub use backgrounds::*;
 pub use borders::*;
 pub use effects::*;
@@ -24,7 +27,7 @@ pub use filters::*;
 pub use flexbox_grid::*;
 pub use interactivity::*;
 pub use layout::*;
-use nom::{branch::alt, combinator::map, IResult};
+pub use plugin::*;
 pub use sizing::*;
 pub use spacing::*;
 pub use svg::*;
@@ -33,7 +36,13 @@ pub use transforms::*;
 pub use transitions_animation::*;
 pub use typography::*;
 
+use crate::css::{Declaration, Node, Rule, Selector};
 use crate::{config::Config, warning::WarningType};
+use nom::branch::alt;
+use nom::bytes::complete::{is_not, tag};
+use nom::combinator::{all_consuming, map, opt, recognize};
+use nom::sequence::{pair, preceded};
+use nom::IResult;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Class<'a> {
@@ -52,154 +61,159 @@ pub enum Class<'a> {
     Borders(Borders<'a>),
     Effects(Effects<'a>),
     Filters(Filter<'a>),
+    ArbitraryProperty(ArbitraryProperty<'a>),
+    /// `group` and `peer`, or a named one like `group/sidebar`, which only mark an element
+    /// for the `group-*` and `peer-*` variants, so they don't have any rules
+    Marker,
+    Plugin(Vec<Declaration>),
 }
 
 pub fn class<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Class<'a>> {
+    // Every category has to consume the whole class, otherwise a category that only
+    // matches a prefix, like `flex` in `flex-row`, would shadow the ones after it
     alt((
-        map(accessibility, Class::Accessibility),
-        map(|i| backgrounds(i, config), Class::Backgrounds),
-        map(|i| borders(i, config), Class::Borders),
+        all_consuming(map(|i| spacing(i, config), Class::Spacing)),
+        all_consuming(map(|i| interactivity(i, config), Class::Interactivity)),
+        all_consuming(map(|i| layout(i, config), Class::Layout)),
+        all_consuming(map(|i| flexbox_grid(i, config), Class::FlexboxGrid)),
+        all_consuming(map(|i| sizing(i, config), Class::Sizing)),
+        all_consuming(map(|i| typography(i, config), Class::Typography)),
+        all_consuming(map(|i| backgrounds(i, config), Class::Backgrounds)),
+        all_consuming(map(|i| borders(i, config), Class::Borders)),
+        all_consuming(map(|i| effects(i, config), Class::Effects)),
+        all_consuming(map(|i| filter(i, config), Class::Filters)),
+        all_consuming(map(|i| table(i, config), Class::Table)),
+        all_consuming(map(
+            |i| transitions_animation(i, config),
+            Class::TransitionsAnimation,
+        )),
+        all_consuming(map(|i| transform(i, config), Class::Transform)),
+        all_consuming(map(|i| svg(i, config), Class::Svg)),
+        all_consuming(map(accessibility, Class::Accessibility)),
+        all_consuming(map(arbitrary_property, Class::ArbitraryProperty)),
+        all_consuming(map(marker, |_| Class::Marker)),
     ))(input)
+}
 
-    // map(|i| spacing(i, config), Class::Spacing)(input)
+fn marker(input: &str) -> IResult<&str, &str> {
+    recognize(pair(
+        alt((tag("group"), tag("peer"))),
+        opt(preceded(tag("/"), is_not("/"))),
+    ))(input)
 }
 
-impl<'a> Eq for Class<'a> {}
+// A color class like `bg-red-500/101` only fails because of its opacity,
+// so it's the opacity that is warned about, if the class works with a valid one
+fn opacity_warning(raw_class: &str, config: &Config) -> Option<WarningType> {
+    let (color, Some(opacity)) = colors::split_opacity(raw_class) else {
+        return None;
+    };
 
-impl<'a> Class<'a> {
-    pub fn new(raw_class: &'a str) -> Option<Self> {
-        // let class = if let Some(interactivity) =
-        //     Interactivity::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Interactivity(interactivity)
-        // } else if let Some(layout) =
-        //     Layout::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Layout(layout)
-        // } else if let Some(flexbox_grid) =
-        //     FlexboxGrid::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::FlexboxGrid(flexbox_grid)
-        // } else if let Some(spacing) =
-        //     Spacing::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Spacing(spacing)
-        // } else if let Some(sizing) =
-        //     Sizing::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Sizing(sizing)
-        // } else if let Some(svg) =
-        //     Svg::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Svg(svg)
-        // } else if let Some(table) =
-        //     Table::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Table(table)
-        // } else if let Some(transitions_animation) =
-        //     TransitionsAnimation::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::TransitionsAnimation(transitions_animation)
-        // } else if let Some(transform) =
-        //     Transform::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Transform(transform)
-        // } else if let Some(typography) =
-        //     Typography::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Typography(typography)
-        // } else if let Some(accessibility) = Accessibility::new(value) {
-        //     Self::Accessibility(accessibility)
-        // } else if let Some(effects) =
-        //     Effects::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Effects(effects)
-        // } else if let Some(backgrounds) =
-        //     Backgrounds::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Backgrounds(backgrounds)
-        // } else if let Some(borders) =
-        //     Borders::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Borders(borders)
-        // } else if let Some(filter) =
-        //     Filter::new(value).map_err(|e| Warning::new(raw_class, position, e))?
-        // {
-        //     Self::Filters(filter)
-        // } else {
-        //     return Err(Warning::new(
-        //         raw_class,
-        //         position,
-        //         WarningType::ClassNotFound,
-        //     ));
-        // };
-
-        // Ok(class)
-
-        todo!()
+    let with_valid_opacity = format!("{color}/[1]");
+    if colors::is_opacity(opacity)
+        || all_consuming(|i| class(i, config))(&with_valid_opacity).is_err()
+    {
+        return None;
     }
 
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
-        todo!()
-        // match self {
-        //     Self::Interactivity(c) => c.to_decl(),
-        //     Self::Layout(c) => c.to_decl(),
-        //     Self::FlexboxGrid(c) => c.to_decl(),
-        //     Self::Spacing(c) => todo!(), // c.to_decl(),
-        //     Self::Sizing(c) => c.to_decl(),
-        //     Self::Svg(c) => c.to_decl(),
-        //     Self::Table(c) => c.to_decl(),
-        //     Self::TransitionsAnimation(c) => c.to_decl(),
-        //     Self::Transform(c) => c.to_decl(),
-        //     Self::Typography(c) => c.to_decl(),
-        //     Self::Accessibility(c) => Ok(c.to_decl()),
-        //     Self::Backgrounds(c) => c.to_decl(),
-        //     Self::Borders(c) => c.to_decl(),
-        //     Self::Effects(c) => c.to_decl(),
-        //     Self::Filters(c) => c.to_decl(),
-        // }
-    }
+    Some(WarningType::InvalidArg(
+        opacity.to_string(),
+        "opacity".to_string(),
+        colors::OPACITY_VALUES.to_vec(),
+    ))
 }
 
-pub trait IntoDeclaration {
-    fn to_decl(self) -> Decl;
-}
+impl<'a> Eq for Class<'a> {}
 
-#[derive(Debug, PartialEq, Hash)]
-pub enum Decl {
-    Lit(&'static str),
-    String(String),
-    Double([String; 2]),
-    Triple([String; 3]),
-    Quad([String; 4]),
-    Vec(Vec<String>),
-    LitVec(Vec<&'static str>),
-    FullClass(String),
+impl<'a> Class<'a> {
+    pub fn new(raw_class: &'a str, config: &'a Config) -> Result<Self, WarningType> {
+        match all_consuming(|i| class(i, config))(raw_class) {
+            Ok((_, class)) => Ok(class),
+            Err(_) => match parse_with_plugins(raw_class, config) {
+                Ok(declarations) => Ok(Self::Plugin(declarations)),
+                Err(WarningType::ClassNotFound) => {
+                    Err(opacity_warning(raw_class, config).unwrap_or(WarningType::ClassNotFound))
+                }
+                Err(warning) => Err(warning),
+            },
+        }
+    }
+
+    /// The rule for the class and any nodes that have to follow it,
+    /// like the breakpoints of `container` or the `@keyframes` of an animation
+    pub fn into_nodes(self, selector: Selector, config: &Config) -> Vec<Node> {
+        let mut extra = match &self {
+            Self::Marker => return vec![],
+            Self::Layout(Layout::Container(_)) => {
+                Container::breakpoint_nodes(&selector, config.variants.screens())
+            }
+            Self::TransitionsAnimation(TransitionsAnimation::Animation(a)) => {
+                a.keyframes(config).into_iter().map(Node::from).collect()
+            }
+            _ => vec![],
+        };
+
+        let mut nodes = vec![Rule::new(selector, self.to_decl()).into()];
+        nodes.append(&mut extra);
+        nodes
+    }
 }
 
-impl ToString for Decl {
-    fn to_string(&self) -> String {
+impl<'a> IntoDeclaration for Class<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::Lit(lit) => lit.to_string(),
-            Self::String(s) => s.to_string(),
-            Self::Vec(m) => m.join(";\n    "),
-            Self::FullClass(fc) => fc.to_string(),
-            _ => todo!(),
+            Self::Interactivity(c) => c.to_decl(),
+            Self::Layout(c) => c.to_decl(),
+            Self::Spacing(c) => c.to_decl(),
+            Self::FlexboxGrid(c) => c.to_decl(),
+            Self::Sizing(c) => c.to_decl(),
+            Self::Svg(c) => c.to_decl(),
+            Self::Table(c) => c.to_decl(),
+            Self::TransitionsAnimation(c) => c.to_decl(),
+            Self::Transform(c) => c.to_decl(),
+            Self::Typography(c) => c.to_decl(),
+            Self::Accessibility(c) => c.to_decl(),
+            Self::Backgrounds(c) => c.to_decl(),
+            Self::Borders(c) => c.to_decl(),
+            Self::Effects(c) => c.to_decl(),
+            Self::Filters(c) => c.to_decl(),
+            Self::ArbitraryProperty(c) => c.to_decl(),
+            Self::Marker => vec![],
+            Self::Plugin(declarations) => declarations,
         }
     }
 }
 
-impl Decl {
-    pub fn to_string(self) -> String {
-        match self {
-            Self::Lit(lit) => lit.to_string(),
-            Self::String(s) => s,
-            Self::Triple(t) => t.join(";\n    "),
-            Self::Double(d) => d.join(";\n    "),
-            Self::Quad(q) => q.join(";\n    "),
-            Self::Vec(m) => m.join(";\n    "),
-            Self::LitVec(l) => l.join(";\n    "),
-            Self::FullClass(fc) => fc,
-        }
+pub trait IntoDeclaration {
+    fn to_decl(self) -> Vec<Declaration>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opacity_warning() {
+        let config = Config::default();
+        let warning = |class| Class::new(class, &config).unwrap_err();
+
+        assert_eq!(
+            warning("bg-red-500/101"),
+            WarningType::InvalidArg(
+                "101".into(),
+                "opacity".into(),
+                colors::OPACITY_VALUES.to_vec()
+            )
+        );
+        assert_eq!(
+            warning("text-sky-500/[abc]"),
+            WarningType::InvalidArg(
+                "[abc]".into(),
+                "opacity".into(),
+                colors::OPACITY_VALUES.to_vec()
+            )
+        );
+        assert_eq!(warning("w-1/200"), WarningType::ClassNotFound);
+        assert_eq!(warning("bg-nope/50"), WarningType::ClassNotFound);
     }
 }
@@ -0,0 +1,30 @@
+use crate::config::Config;
+use crate::css::Declaration;
+use crate::warning::WarningType;
+
+/// A utility that isn't built in, like `elevation-2`. Plugins are only tried when none of
+/// the built in utilities match, in the order they were added to `Config::utilities`.
+/// Their rules get the same variants, ordering and warnings as the built in ones.
+pub trait UtilityPlugin: Send {
+    /// The declarations of `class`, which is the class without its variants, like `elevation-2`
+    /// in `hover:elevation-2`. Returns `WarningType::ClassNotFound` if the plugin doesn't
+    /// know the class, or another warning if it does, but the value is wrong.
+    fn parse(&self, class: &str, config: &Config) -> Result<Vec<Declaration>, WarningType>;
+}
+
+/// Runs the plugins in order. The first warning that isn't `ClassNotFound` is kept,
+/// so `elevation-99` reports the missing value instead of a missing class.
+pub fn parse_with_plugins(class: &str, config: &Config) -> Result<Vec<Declaration>, WarningType> {
+    let mut warning = WarningType::ClassNotFound;
+
+    for plugin in &config.utilities {
+        match plugin.parse(class, config) {
+            Ok(declarations) => return Ok(declarations),
+            Err(WarningType::ClassNotFound) => (),
+            Err(w) if warning == WarningType::ClassNotFound => warning = w,
+            Err(_) => (),
+        }
+    }
+
+    Err(warning)
+}
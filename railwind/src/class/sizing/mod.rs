@@ -1,86 +1,86 @@
-mod types;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::IResult;
 
-use types::*;
+use crate::class::utils::keyword_value;
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::Declaration;
 
-use crate::class::Decl;
-use crate::utils::{get_args, get_class_name};
-use crate::warning::WarningType;
+#[derive(Debug, PartialEq, Hash)]
+pub enum Sizing<'a> {
+    Width(&'a str),
+    MinWidth(&'a str),
+    MaxWidth(&'a str),
+    Height(&'a str),
+    MinHeight(&'a str),
+    MaxHeight(&'a str),
+}
 
-use lazy_static::lazy_static;
-use std::collections::HashMap;
+pub fn sizing<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Sizing<'a>> {
+    let sizing = &config.sizing;
 
-lazy_static! {
-    pub static ref WIDTH: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("width.ron")).unwrap();
-    pub static ref MIN_WIDTH: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("min_width.ron")).unwrap();
-    pub static ref MAX_WIDTH: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("max_width.ron")).unwrap();
-    pub static ref HEIGHT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("height.ron")).unwrap();
-    pub static ref MIN_HEIGHT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("min_height.ron")).unwrap();
-    pub static ref MAX_HEIGHT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("max_height.ron")).unwrap();
+    alt((
+        map(keyword_value("w", sizing.get_width()), Sizing::Width),
+        map(
+            keyword_value("min-w", sizing.get_min_width()),
+            Sizing::MinWidth,
+        ),
+        map(
+            keyword_value("max-w", sizing.get_max_width()),
+            Sizing::MaxWidth,
+        ),
+        map(keyword_value("h", sizing.get_height()), Sizing::Height),
+        map(
+            keyword_value("min-h", sizing.get_min_height()),
+            Sizing::MinHeight,
+        ),
+        map(
+            keyword_value("max-h", sizing.get_max_height()),
+            Sizing::MaxHeight,
+        ),
+    ))(input)
 }
 
-#[derive(Debug, PartialEq, Hash)]
-pub enum Sizing<'a> {
-    Width(Width<'a>),
-    MinWidth(MinWidth<'a>),
-    MaxWidth(MaxWidth<'a>),
-    Height(Height<'a>),
-    MinHeight(MinHeight<'a>),
-    MaxHeight(MaxHeight<'a>),
+impl<'a> IntoDeclaration for Sizing<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Width(s) => size_decl("width", s),
+            Self::MinWidth(s) => size_decl("min-width", s),
+            Self::MaxWidth(s) => size_decl("max-width", s),
+            Self::Height(s) => size_decl("height", s),
+            Self::MinHeight(s) => size_decl("min-height", s),
+            Self::MaxHeight(s) => size_decl("max-height", s),
+        }
+    }
 }
 
-impl<'a> Sizing<'a> {
-    pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-        let args = if let Ok(str) = get_args(value) {
-            str
-        } else {
-            return Ok(None);
-        };
+// Firefox still needs the prefixed `fit-content` as a fallback
+fn size_decl(property: &str, value: &str) -> Vec<Declaration> {
+    if value == "fit-content" {
+        return vec![
+            Declaration::new(property, "-moz-fit-content"),
+            Declaration::new(property, value),
+        ];
+    }
 
-        let sizing = match get_class_name(value) {
-            "w" => Self::Width(Width(args)),
-            "h" => Self::Height(Height(args)),
-            "min" => match get_class_name(args) {
-                "w" => Self::MinWidth(MinWidth(get_args(args)?)),
-                "h" => Self::MinHeight(MinHeight(get_args(args)?)),
-                v => {
-                    return Err(WarningType::InvalidArg(
-                        v.into(),
-                        "Min Width / Height".into(),
-                        vec!["w", "h"],
-                    ))
-                }
-            },
-            "max" => match get_class_name(args) {
-                "w" => Self::MaxWidth(MaxWidth(get_args(args)?)),
-                "h" => Self::MaxHeight(MaxHeight(get_args(args)?)),
-                v => {
-                    return Err(WarningType::InvalidArg(
-                        v.into(),
-                        "Max Width / Height".into(),
-                        vec!["w", "h"],
-                    ))
-                }
-            },
-            _ => return Ok(None),
-        };
+    vec![Declaration::new(property, value)]
+}
 
-        Ok(Some(sizing))
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
-        match self {
-            Self::Width(s) => s.to_decl(),
-            Self::MinWidth(s) => s.to_decl(),
-            Self::MaxWidth(s) => s.to_decl(),
-            Self::Height(s) => s.to_decl(),
-            Self::MinHeight(s) => s.to_decl(),
-            Self::MaxHeight(s) => s.to_decl(),
-        }
+    #[test]
+    fn test_sizing() {
+        assert_eq!(
+            sizing("w-1/2", &Config::default()),
+            Ok(("", Sizing::Width("50%")))
+        );
+        assert_eq!(
+            sizing("max-h-[32rem]", &Config::default()),
+            Ok(("", Sizing::MaxHeight("32rem")))
+        );
+        assert!(sizing("min-w-x", &Config::default()).is_err());
     }
 }
@@ -3,10 +3,10 @@ use nom::branch::alt;
 use nom::IResult;
 use nom::{bytes::complete::tag, combinator::map};
 
-use crate::class::utils::{keyword_value, neg_keyword_value};
-use crate::class::Decl;
+use crate::class::utils::neg_keyword_value;
 use crate::class::IntoDeclaration;
 use crate::config::Config;
+use crate::css::Declaration;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Spacing<'a> {
@@ -24,7 +24,7 @@ pub fn spacing<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Spaci
 }
 
 impl<'a> IntoDeclaration for Spacing<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Spacing::Padding(s) => s.to_decl(),
             Spacing::Margin(s) => s.to_decl(),
@@ -54,21 +54,21 @@ pub enum Padding<'a> {
 }
 
 impl<'a> IntoDeclaration for Padding<'a> {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::All(p) => Decl::String(format!("padding: {}", p)),
-            Self::Top(p) => Decl::String(format!("padding-top: {}", p)),
-            Self::Right(p) => Decl::String(format!("padding-right: {}", p)),
-            Self::Bottom(p) => Decl::String(format!("padding-bottom: {}", p)),
-            Self::Left(p) => Decl::String(format!("padding-left: {}", p)),
-            Self::X(p) => Decl::Double([
-                format!("padding-left: {}", p),
-                format!("padding-right: {}", p),
-            ]),
-            Self::Y(p) => Decl::Double([
-                format!("padding-top: {}", p),
-                format!("padding-bottom: {}", p),
-            ]),
+            Self::All(p) => vec![Declaration::new("padding", p)],
+            Self::Top(p) => vec![Declaration::new("padding-top", p)],
+            Self::Right(p) => vec![Declaration::new("padding-right", p)],
+            Self::Bottom(p) => vec![Declaration::new("padding-bottom", p)],
+            Self::Left(p) => vec![Declaration::new("padding-left", p)],
+            Self::X(p) => vec![
+                Declaration::new("padding-left", p),
+                Declaration::new("padding-right", p),
+            ],
+            Self::Y(p) => vec![
+                Declaration::new("padding-top", p),
+                Declaration::new("padding-bottom", p),
+            ],
         }
     }
 }
@@ -99,21 +99,21 @@ fn margin<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Margin> {
 }
 
 impl IntoDeclaration for Margin {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::All(m) => Decl::String(format!("margin: {}", m)),
-            Self::Top(m) => Decl::String(format!("margin-top: {}", m)),
-            Self::Right(m) => Decl::String(format!("margin-right: {}", m)),
-            Self::Bottom(m) => Decl::String(format!("margin-bottom: {}", m)),
-            Self::Left(m) => Decl::String(format!("margin-left: {}", m)),
-            Self::X(m) => Decl::Double([
-                format!("margin-left: {}", m),
-                format!("margin-right: {}", m),
-            ]),
-            Self::Y(m) => Decl::Double([
-                format!("margin-top: {}", m),
-                format!("margin-bottom: {}", m),
-            ]),
+            Self::All(m) => vec![Declaration::new("margin", m)],
+            Self::Top(m) => vec![Declaration::new("margin-top", m)],
+            Self::Right(m) => vec![Declaration::new("margin-right", m)],
+            Self::Bottom(m) => vec![Declaration::new("margin-bottom", m)],
+            Self::Left(m) => vec![Declaration::new("margin-left", m)],
+            Self::X(m) => vec![
+                Declaration::new("margin-left", &m),
+                Declaration::new("margin-right", m),
+            ],
+            Self::Y(m) => vec![
+                Declaration::new("margin-top", &m),
+                Declaration::new("margin-bottom", m),
+            ],
         }
     }
 }
@@ -138,26 +138,32 @@ fn space_between<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Spa
 }
 
 impl IntoDeclaration for SpaceBetween {
-    fn to_decl(self) -> Decl {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            SpaceBetween::X(s) => Decl::Vec(vec![
-                "--tw-space-x-reverse: 0".into(),
-                format!("margin-right: calc({} * var(--tw-space-x-reverse))", s),
-                format!(
-                    "margin-left: calc({} * calc(1 - var(--tw-space-x-reverse)))",
-                    s
+            SpaceBetween::X(s) => vec![
+                Declaration::new("--tw-space-x-reverse", "0"),
+                Declaration::new(
+                    "margin-right",
+                    format!("calc({} * var(--tw-space-x-reverse))", s),
                 ),
-            ]),
-            SpaceBetween::Y(s) => Decl::Vec(vec![
-                "--tw-space-y-reverse: 0".into(),
-                format!(
-                    "margin-top: calc({} * calc(1 - var(--tw-space-y-reverse)))",
-                    s
+                Declaration::new(
+                    "margin-left",
+                    format!("calc({} * calc(1 - var(--tw-space-x-reverse)))", s),
                 ),
-                format!("margin-bottom: calc({} * var(--tw-space-y-reverse))", s),
-            ]),
-            SpaceBetween::ReverseX => Decl::Lit("--tw-space-x-reverse: 1"),
-            SpaceBetween::ReverseY => Decl::Lit("--tw-space-y-reverse: 1"),
+            ],
+            SpaceBetween::Y(s) => vec![
+                Declaration::new("--tw-space-y-reverse", "0"),
+                Declaration::new(
+                    "margin-top",
+                    format!("calc({} * calc(1 - var(--tw-space-y-reverse)))", s),
+                ),
+                Declaration::new(
+                    "margin-bottom",
+                    format!("calc({} * var(--tw-space-y-reverse))", s),
+                ),
+            ],
+            SpaceBetween::ReverseX => vec![Declaration::new("--tw-space-x-reverse", "1")],
+            SpaceBetween::ReverseY => vec![Declaration::new("--tw-space-y-reverse", "1")],
         }
     }
 }
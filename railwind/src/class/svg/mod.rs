@@ -1,18 +1,14 @@
-mod types;
+use macro_derive::{EnumParser, IntoDeclaration};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::map;
+use nom::sequence::preceded;
+use nom::IResult;
 
-use types::*;
-
-use crate::class::Decl;
-use crate::utils::{get_args, get_class_name};
-use crate::warning::WarningType;
-
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-lazy_static! {
-    pub static ref COLORS: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("../colors.ron")).unwrap();
-}
+use crate::class::colors::{color, Color};
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::Declaration;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Svg<'a> {
@@ -21,36 +17,63 @@ pub enum Svg<'a> {
     StrokeWidth(StrokeWidth),
 }
 
-impl<'a> Svg<'a> {
-    pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-        let args = if let Ok(str) = get_args(value) {
-            str
-        } else {
-            return Ok(None);
-        };
-
-        let class_name = get_class_name(value);
-
-        let svg = match class_name {
-            "fill" => Self::Fill(Fill(args)),
-            "stroke" => {
-                if let Some(stroke) = StrokeWidth::new(args) {
-                    Self::StrokeWidth(stroke)
-                } else {
-                    Self::Stroke(Stroke::new(args))
-                }
-            }
-            _ => return Ok(None),
-        };
-
-        Ok(Some(svg))
-    }
+pub fn svg<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Svg<'a>> {
+    alt((
+        preceded(tag("fill-"), map(|i| fill(i, config), Svg::Fill)),
+        preceded(
+            tag("stroke-"),
+            alt((
+                map(stroke_width, Svg::StrokeWidth),
+                map(|i| stroke(i, config), Svg::Stroke),
+            )),
+        ),
+    ))(input)
+}
 
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
+impl<'a> IntoDeclaration for Svg<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Self::Fill(s) => s.to_decl(),
             Self::Stroke(s) => s.to_decl(),
-            Self::StrokeWidth(s) => Ok(s.to_decl()),
+            Self::StrokeWidth(s) => s.to_decl(),
         }
     }
 }
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct Fill<'a>(pub Color<'a>);
+
+fn fill<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Fill<'a>> {
+    map(color(config.svg.get_fill()), Fill)(input)
+}
+
+impl<'a> IntoDeclaration for Fill<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("fill", self.0.value(None))]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct Stroke<'a>(pub Color<'a>);
+
+fn stroke<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Stroke<'a>> {
+    map(color(config.svg.get_stroke()), Stroke)(input)
+}
+
+impl<'a> IntoDeclaration for Stroke<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("stroke", self.0.value(None))]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(stroke_width)]
+#[decl("stroke-width")]
+pub enum StrokeWidth {
+    #[tag("0")]
+    Zero,
+    #[tag("1")]
+    One,
+    #[tag("2")]
+    Two,
+}
@@ -1,54 +1,109 @@
-mod types;
+use macro_derive::{EnumParser, IntoDeclaration};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::map;
+use nom::sequence::preceded;
+use nom::IResult;
 
-use types::*;
+use crate::class::utils::{arbitrary_hashmap_value, keyword_value};
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::Declaration;
 
-use crate::class::Decl;
-use crate::utils::{get_args, get_class_name};
-use crate::warning::WarningType;
-
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-lazy_static! {
-    pub static ref BORDER_SPACING: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("border_spacing.ron")).unwrap();
-}
+const BORDER_SPACING_STYLE: (&str, &str) = (
+    "border-spacing",
+    "var(--tw-border-spacing-x) var(--tw-border-spacing-y)",
+);
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Table<'a> {
     BorderSpacing(BorderSpacing<'a>),
     BorderCollapse(BorderCollapse),
-    Layout(Layout),
-}
-
-impl<'a> Table<'a> {
-    pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-        let args = if let Ok(str) = get_args(value) {
-            str
-        } else {
-            return Ok(None);
-        };
-
-        let class_name = get_class_name(value);
-
-        let table = match class_name {
-            "border" => match get_class_name(args) {
-                "collapse" => Self::BorderCollapse(BorderCollapse::Collapse),
-                "separate" => Self::BorderCollapse(BorderCollapse::Separate),
-                "spacing" => Self::BorderSpacing(BorderSpacing::new(get_args(args)?)),
-                _ => return Ok(None),
-            },
-            "table" => Self::Layout(Layout::new(args)?),
-            _ => return Ok(None),
-        };
-
-        Ok(Some(table))
-    }
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
+    TableLayout(TableLayout),
+}
+
+pub fn table<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Table<'a>> {
+    alt((
+        preceded(
+            tag("border-"),
+            alt((
+                map(border_collapse, Table::BorderCollapse),
+                preceded(
+                    tag("spacing"),
+                    map(|i| border_spacing(i, config), Table::BorderSpacing),
+                ),
+            )),
+        ),
+        preceded(tag("table-"), map(table_layout, Table::TableLayout)),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for Table<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Self::BorderSpacing(t) => t.to_decl(),
-            Self::BorderCollapse(t) => Ok(t.to_decl()),
-            Self::Layout(t) => Ok(t.to_decl()),
+            Self::BorderCollapse(t) => t.to_decl(),
+            Self::TableLayout(t) => t.to_decl(),
         }
     }
 }
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(border_collapse)]
+#[decl("border-collapse")]
+pub enum BorderCollapse {
+    #[tag("collapse")]
+    Collapse,
+    #[tag("separate")]
+    Separate,
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum BorderSpacing<'a> {
+    All(&'a str),
+    X(&'a str),
+    Y(&'a str),
+}
+
+fn border_spacing<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, BorderSpacing<'a>> {
+    let border_spacing = config.tables.get_border_spacing();
+
+    alt((
+        map(keyword_value("-x", border_spacing), BorderSpacing::X),
+        map(keyword_value("-y", border_spacing), BorderSpacing::Y),
+        preceded(
+            tag("-"),
+            map(arbitrary_hashmap_value(border_spacing), BorderSpacing::All),
+        ),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for BorderSpacing<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::X(s) => vec![
+                Declaration::new("--tw-border-spacing-x", s),
+                BORDER_SPACING_STYLE.into(),
+            ],
+            Self::Y(s) => vec![
+                Declaration::new("--tw-border-spacing-y", s),
+                BORDER_SPACING_STYLE.into(),
+            ],
+            Self::All(s) => vec![
+                Declaration::new("--tw-border-spacing-x", s),
+                Declaration::new("--tw-border-spacing-y", s),
+                BORDER_SPACING_STYLE.into(),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(table_layout)]
+#[decl("table-layout")]
+pub enum TableLayout {
+    #[tag("auto")]
+    Auto,
+    #[tag("fixed")]
+    Fixed,
+}
@@ -1,90 +1,130 @@
-mod types;
-
-use types::*;
-
-use crate::class::Decl;
-use crate::utils::{get_args, get_class_name};
-use crate::warning::WarningType;
-
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-lazy_static! {
-    pub static ref TRANSLATE: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("translate.ron")).unwrap();
-    pub static ref ROTATE: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("rotate.ron")).unwrap();
-    pub static ref SKEW: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("skew.ron")).unwrap();
-    pub static ref SCALE: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("scale.ron")).unwrap();
-    pub static ref ORIGIN: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("origin.ron")).unwrap();
-}
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::map;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::class::utils::{arbitrary_hashmap_value, neg_keyword_value};
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::Declaration;
+
+const TRANSFORM_STYLE: (&str, &str) = ("transform", "translate(var(--tw-translate-x), var(--tw-translate-y)) rotate(var(--tw-rotate)) skewX(var(--tw-skew-x)) skewY(var(--tw-skew-y)) scaleX(var(--tw-scale-x)) scaleY(var(--tw-scale-y))");
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Transform<'a> {
-    TranslateX(TranslateX<'a>),
-    TranslateY(TranslateY<'a>),
-    Rotate(Rotate<'a>),
-    SkewX(SkewX<'a>),
-    SkewY(SkewY<'a>),
-    Scale(Scale<'a>),
+    TranslateX(String),
+    TranslateY(String),
+    Rotate(String),
+    SkewX(String),
+    SkewY(String),
+    Scale(Scale),
     Origin(Origin<'a>),
 }
 
-impl<'a> Transform<'a> {
-    pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-        let args = if let Ok(str) = get_args(value) {
-            str
-        } else {
-            return Ok(None);
-        };
-
-        let class_name = get_class_name(value);
-
-        let transform = match class_name {
-            "translate" | "-translate" => match get_class_name(args) {
-                "x" => Self::TranslateX(TranslateX::new(class_name, get_args(args)?)),
-
-                "y" => Self::TranslateY(TranslateY::new(class_name, get_args(args)?)),
-                v => {
-                    return Err(WarningType::InvalidArg(
-                        v.into(),
-                        "Translate X / Y".into(),
-                        vec!["x", "y"],
-                    ))
-                }
-            },
-            "rotate" | "-rotate" => Self::Rotate(Rotate::new(class_name, args)),
-            "skew" | "-skew" => match get_class_name(args) {
-                "x" => Self::SkewX(SkewX::new(class_name, get_args(args)?)),
-
-                "y" => Self::SkewY(SkewY::new(class_name, get_args(args)?)),
-                v => {
-                    return Err(WarningType::InvalidArg(
-                        v.into(),
-                        "Skew X / Y".into(),
-                        vec!["x", "y"],
-                    ))
-                }
-            },
-            "scale" | "-scale" => Self::Scale(Scale::new(value)?),
-            "origin" => Self::Origin(Origin(args)),
-            _ => return Ok(None),
-        };
-
-        Ok(Some(transform))
+pub fn transform<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Transform<'a>> {
+    let translate = config.transforms.get_translate();
+    let rotate = config.transforms.get_rotate();
+    let skew = config.transforms.get_skew();
+
+    alt((
+        map(
+            neg_keyword_value("translate-x", translate),
+            Transform::TranslateX,
+        ),
+        map(
+            neg_keyword_value("translate-y", translate),
+            Transform::TranslateY,
+        ),
+        map(neg_keyword_value("rotate", rotate), Transform::Rotate),
+        map(neg_keyword_value("skew-x", skew), Transform::SkewX),
+        map(neg_keyword_value("skew-y", skew), Transform::SkewY),
+        map(|i| scale(i, config), Transform::Scale),
+        preceded(
+            tag("origin-"),
+            map(|i| origin(i, config), Transform::Origin),
+        ),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for Transform<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::TranslateX(t) => transform_decl("--tw-translate-x", t),
+            Self::TranslateY(t) => transform_decl("--tw-translate-y", t),
+            Self::Rotate(t) => transform_decl("--tw-rotate", t),
+            Self::SkewX(t) => transform_decl("--tw-skew-x", t),
+            Self::SkewY(t) => transform_decl("--tw-skew-y", t),
+            Self::Scale(t) => t.to_decl(),
+            Self::Origin(t) => t.to_decl(),
+        }
     }
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
+}
+
+fn transform_decl(variable: &str, value: String) -> Vec<Declaration> {
+    vec![Declaration::new(variable, value), TRANSFORM_STYLE.into()]
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum Scale {
+    All(String),
+    X(String),
+    Y(String),
+}
+
+fn scale<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Scale> {
+    let scale = config.transforms.get_scale();
+
+    alt((
+        map(neg_keyword_value("scale-x", scale), Scale::X),
+        map(neg_keyword_value("scale-y", scale), Scale::Y),
+        map(neg_keyword_value("scale", scale), Scale::All),
+    ))(input)
+}
+
+impl IntoDeclaration for Scale {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::TranslateX(s) => s.to_decl(),
-            Self::TranslateY(s) => s.to_decl(),
-            Self::Rotate(s) => s.to_decl(),
-            Self::SkewX(s) => s.to_decl(),
-            Self::SkewY(s) => s.to_decl(),
-            Self::Scale(s) => s.to_decl(),
-            Self::Origin(s) => s.to_decl(),
+            Self::All(s) => vec![
+                Declaration::new("--tw-scale-x", &s),
+                Declaration::new("--tw-scale-y", s),
+                TRANSFORM_STYLE.into(),
+            ],
+            Self::X(s) => transform_decl("--tw-scale-x", s),
+            Self::Y(s) => transform_decl("--tw-scale-y", s),
         }
     }
 }
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct Origin<'a>(pub &'a str);
+
+fn origin<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Origin<'a>> {
+    map(
+        arbitrary_hashmap_value(config.transforms.get_origin()),
+        Origin,
+    )(input)
+}
+
+impl<'a> IntoDeclaration for Origin<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("transform-origin", self.0)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform() {
+        assert_eq!(
+            transform("-translate-x-1/2", &Config::default()),
+            Ok(("", Transform::TranslateX("-50%".into())))
+        );
+        assert_eq!(
+            transform("scale-x-[2]", &Config::default()),
+            Ok(("", Transform::Scale(Scale::X("2".into()))))
+        );
+    }
+}
@@ -1,50 +1,63 @@
-mod types;
+use macro_derive::{EnumParser, IntoDeclaration};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::{eof, map};
+use nom::sequence::preceded;
+use nom::IResult;
 
-use types::*;
+use crate::class::utils::{arbitrary, arbitrary_hashmap_value, hashmap_value};
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::{AtRule, Declaration, Rule, Selector};
 
-use crate::class::Decl;
-use crate::utils::{get_args, get_class_name, get_opt_args};
-use crate::warning::WarningType;
-
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-lazy_static! {
-    pub static ref DELAY: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("delay.ron")).unwrap();
-    pub static ref DURATION: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("duration.ron")).unwrap();
-    pub static ref TIMING_FUNCTION: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("timing_function.ron")).unwrap();
-    pub static ref ANIMATION: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("animation.ron")).unwrap();
-}
+const TIMING_FUNCTION_STYLE: (&str, &str) =
+    ("transition-timing-function", "cubic-bezier(0.4, 0, 0.2, 1)");
+const DURATION_STYLE: (&str, &str) = ("transition-duration", "150ms");
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum TransitionsAnimation<'a> {
-    Transition(Transition),
+    Transition(Transition<'a>),
     Duration(Duration<'a>),
     TimingFunction(TimingFunction<'a>),
     Delay(Delay<'a>),
     Animation(Animation<'a>),
 }
 
-impl<'a> TransitionsAnimation<'a> {
-    pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-        let transitions_animation = match get_class_name(value) {
-            "transition" => Self::Transition(Transition::new(get_opt_args(value))?),
-            "duration" => Self::Duration(Duration(get_args(value)?)),
-            "ease" => Self::TimingFunction(TimingFunction(get_args(value)?)),
-            "delay" => Self::Delay(Delay(get_args(value)?)),
-            "animate" => Self::Animation(Animation(get_args(value)?)),
-            _ => return Ok(None),
-        };
+pub fn transitions_animation<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, TransitionsAnimation<'a>> {
+    alt((
+        preceded(
+            tag("transition"),
+            map(transition, TransitionsAnimation::Transition),
+        ),
+        preceded(
+            tag("duration-"),
+            map(|i| duration(i, config), TransitionsAnimation::Duration),
+        ),
+        preceded(
+            tag("ease-"),
+            map(
+                |i| timing_function(i, config),
+                TransitionsAnimation::TimingFunction,
+            ),
+        ),
+        preceded(
+            tag("delay-"),
+            map(|i| delay(i, config), TransitionsAnimation::Delay),
+        ),
+        preceded(
+            tag("animate-"),
+            map(|i| animation(i, config), TransitionsAnimation::Animation),
+        ),
+    ))(input)
+}
 
-        Ok(Some(transitions_animation))
-    }
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
+impl<'a> IntoDeclaration for TransitionsAnimation<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
-            Self::Transition(s) => Ok(s.to_decl()),
+            Self::Transition(s) => s.to_decl(),
             Self::Duration(s) => s.to_decl(),
             Self::TimingFunction(s) => s.to_decl(),
             Self::Delay(s) => s.to_decl(),
@@ -52,3 +65,154 @@ impl<'a> TransitionsAnimation<'a> {
         }
     }
 }
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum Transition<'a> {
+    Default,
+    Property(TransitionProperty),
+    Arbitrary(&'a str),
+}
+
+fn transition(input: &str) -> IResult<&str, Transition<'_>> {
+    alt((
+        map(eof, |_| Transition::Default),
+        preceded(
+            tag("-"),
+            alt((
+                map(transition_property, Transition::Property),
+                map(arbitrary, Transition::Arbitrary),
+            )),
+        ),
+    ))(input)
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(transition_property)]
+pub enum TransitionProperty {
+    #[tag("none")]
+    None,
+    #[tag("all")]
+    All,
+    #[tag("colors")]
+    Colors,
+    #[tag("opacity")]
+    Opacity,
+    #[tag("shadow")]
+    Shadow,
+    #[tag("transform")]
+    Transform,
+}
+
+impl<'a> IntoDeclaration for Transition<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Default => vec![
+                Declaration::new("transition-property", "color, background-color, border-color, outline-color, fill, stroke, opacity, box-shadow, transform, filter, -webkit-text-decoration-color, -webkit-backdrop-filter"),
+                Declaration::new("transition-property", "color, background-color, border-color, outline-color, text-decoration-color, fill, stroke, opacity, box-shadow, transform, filter, backdrop-filter"),
+                Declaration::new("transition-property", "color, background-color, border-color, outline-color, text-decoration-color, fill, stroke, opacity, box-shadow, transform, filter, backdrop-filter, -webkit-text-decoration-color, -webkit-backdrop-filter"),
+                TIMING_FUNCTION_STYLE.into(),
+                DURATION_STYLE.into(),
+            ],
+            Self::Property(TransitionProperty::None) => vec![Declaration::new("transition-property", "none")],
+            Self::Property(TransitionProperty::All) => transition_decl("all"),
+            Self::Property(TransitionProperty::Colors) => vec![
+                Declaration::new("transition-property", "color, background-color, border-color, outline-color, fill, stroke, -webkit-text-decoration-color"),
+                Declaration::new("transition-property", "color, background-color, border-color, outline-color, text-decoration-color, fill, stroke"),
+                Declaration::new("transition-property", "color, background-color, border-color, outline-color, text-decoration-color, fill, stroke, -webkit-text-decoration-color"),
+                TIMING_FUNCTION_STYLE.into(),
+                DURATION_STYLE.into(),
+            ],
+            Self::Property(TransitionProperty::Opacity) => transition_decl("opacity"),
+            Self::Property(TransitionProperty::Shadow) => transition_decl("box-shadow"),
+            Self::Property(TransitionProperty::Transform) => transition_decl("transform"),
+            Self::Arbitrary(v) => transition_decl(v),
+        }
+    }
+}
+
+fn transition_decl(property: &str) -> Vec<Declaration> {
+    vec![
+        Declaration::new("transition-property", property),
+        TIMING_FUNCTION_STYLE.into(),
+        DURATION_STYLE.into(),
+    ]
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("transition-duration")]
+pub struct Duration<'a>(pub &'a str);
+
+fn duration<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Duration<'a>> {
+    let duration = config.transitions_animation.get_duration();
+    map(arbitrary_hashmap_value(duration), Duration)(input)
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("transition-timing-function")]
+pub struct TimingFunction<'a>(pub &'a str);
+
+fn timing_function<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, TimingFunction<'a>> {
+    let timing_function = config.transitions_animation.get_timing_function();
+    map(arbitrary_hashmap_value(timing_function), TimingFunction)(input)
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("transition-delay")]
+pub struct Delay<'a>(pub &'a str);
+
+fn delay<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Delay<'a>> {
+    let delay = config.transitions_animation.get_delay();
+    map(arbitrary_hashmap_value(delay), Delay)(input)
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum Animation<'a> {
+    Named(&'a str),
+    Arbitrary(&'a str),
+}
+
+fn animation<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Animation<'a>> {
+    alt((
+        map(arbitrary, Animation::Arbitrary),
+        map(
+            hashmap_value(config.transitions_animation.get_animation()),
+            Animation::Named,
+        ),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for Animation<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Named(a) | Self::Arbitrary(a) => vec![Declaration::new("animation", a)],
+        }
+    }
+}
+
+impl<'a> Animation<'a> {
+    /// The `@keyframes` for the animation name at the start of the value, if it has any
+    pub fn keyframes(&self, config: &Config) -> Option<AtRule> {
+        let (Self::Named(a) | Self::Arbitrary(a)) = self;
+        let name = a.split_whitespace().next()?;
+
+        let keyframes = config.transitions_animation.get_keyframes();
+
+        keyframes.get(name).map(|frames| {
+            let nodes = frames
+                .iter()
+                .map(|(selector, decls)| {
+                    Rule::new(
+                        Selector::new(selector),
+                        decls
+                            .iter()
+                            .map(|(property, value)| Declaration::new(property, value))
+                            .collect(),
+                    )
+                    .into()
+                })
+                .collect();
+
+            AtRule::keyframes(name, nodes)
+        })
+    }
+}
@@ -1,42 +1,17 @@
-mod types;
+use macro_derive::{EnumParser, IntoDeclaration};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::{map, map_opt, rest, verify};
+use nom::sequence::preceded;
+use nom::IResult;
 
-use types::*;
-
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-use super::{utils::value_is_size, Decl};
-use crate::{
-    utils::{get_args, get_class_name},
-    warning::WarningType,
+use crate::class::colors::{color, is_color, Color};
+use crate::class::utils::{
+    arbitrary, arbitrary_hashmap_value, hashmap_value, neg_keyword_value, strip_hint,
 };
-
-lazy_static! {
-    pub static ref FONT_FAMILY: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("font_family.ron")).unwrap();
-    pub static ref FONT_SIZE: HashMap<&'static str, (&'static str, &'static str)> =
-        ron::from_str(include_str!("font_size.ron")).unwrap();
-    pub static ref FONT_WEIGHT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("font_weight.ron")).unwrap();
-    pub static ref LETTER_SPACING: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("letter_spacing.ron")).unwrap();
-    pub static ref LINE_HEIGHT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("line_height.ron")).unwrap();
-    pub static ref LINE_STYLE_TYPE: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("line_style_type.ron")).unwrap();
-    pub static ref TEXT_COLOR: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("../colors.ron")).unwrap();
-    pub static ref TEXT_DECORATION_COLOR: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("../colors.ron")).unwrap();
-    pub static ref TEXT_DECORATION_THICKNESS: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("text_decoration_thickness.ron")).unwrap();
-    pub static ref TEXT_UNDERLINE_OFFSET: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("text_underline_offset.ron")).unwrap();
-    pub static ref TEXT_INDENT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("text_indent.ron")).unwrap();
-    pub static ref CONTENT: HashMap<&'static str, &'static str> =
-        ron::from_str(include_str!("content.ron")).unwrap();
-}
+use crate::class::IntoDeclaration;
+use crate::config::Config;
+use crate::css::Declaration;
 
 #[derive(Debug, PartialEq, Hash)]
 pub enum Typography<'a> {
@@ -46,7 +21,7 @@ pub enum Typography<'a> {
     FontStyle(FontStyle),
     FontWeight(FontWeight<'a>),
     FontVariantNumeric(FontVariantNumeric),
-    LetterSpacing(LetterSpacing<'a>),
+    LetterSpacing(LetterSpacing),
     LineHeight(LineHeight<'a>),
     LineStyleType(LineStyleType<'a>),
     ListStylePosition(ListStylePosition),
@@ -59,133 +34,639 @@ pub enum Typography<'a> {
     TextUnderlineOffset(TextUnderlineOffset<'a>),
     TextTransform(TextTransform),
     TextOverflow(TextOverflow),
-    TextIndent(TextIndent<'a>),
-    VerticalAlign(VerticalAlign),
+    TextIndent(TextIndent),
+    VerticalAlign(VerticalAlign<'a>),
     Whitespace(Whitespace),
     WordBreak(WordBreak),
     Content(Content<'a>),
 }
 
-impl<'a> Typography<'a> {
-    pub fn new(value: &'a str) -> Result<Option<Self>, WarningType> {
-        let typography = match get_class_name(value) {
-            "font" => {
-                let args = get_args(value)?;
-                if FONT_FAMILY.contains_key(args)
-                    || (args.starts_with("['") && args.ends_with("']"))
-                {
-                    Self::FontFamily(FontFamily(get_args(value)?))
-                } else {
-                    Self::FontWeight(FontWeight(get_args(value)?))
-                }
-            }
-            "text" => {
-                if let Some(text_align) = TextAlign::new(get_args(value)?) {
-                    Self::TextAlign(text_align)
-                } else if let Some(text_overflow) = TextOverflow::new(get_args(value)?) {
-                    Self::TextOverflow(text_overflow)
-                } else if FONT_SIZE.contains_key(get_args(value)?)
-                    || value_is_size(get_args(value)?)
-                {
-                    Self::FontSize(FontSize(get_args(value)?))
-                } else {
-                    Self::TextColor(TextColor(get_args(value)?))
-                }
-            }
-            "tracking" => {
-                Self::LetterSpacing(LetterSpacing::new(get_class_name(value), get_args(value)?))
-            }
-            "leading" => Self::LineHeight(LineHeight(get_args(value)?)),
-            "list" => {
-                if let Some(list_style_position) = ListStylePosition::new(get_args(value)?) {
-                    Self::ListStylePosition(list_style_position)
-                } else {
-                    Self::LineStyleType(LineStyleType(get_args(value)?))
-                }
-            }
-            "decoration" => {
-                if let Some(text_decoration) = TextDecoration::new(get_args(value)?) {
-                    Self::TextDecoration(text_decoration)
-                } else if let Some(text_decoration_style) =
-                    TextDecorationStyle::new(get_args(value)?)
-                {
-                    Self::TextDecorationStyle(text_decoration_style)
-                } else if TEXT_DECORATION_THICKNESS.contains_key(get_args(value)?)
-                    || value_is_size(get_args(value)?)
-                {
-                    Self::TextDecorationThickness(TextDecorationThickness(get_args(value)?))
-                } else {
-                    Self::TextDecorationColor(TextDecorationColor(get_args(value)?))
-                }
-            }
-            "underline" => {
-                if let Ok(args) = get_args(value) {
-                    if get_class_name(args) == "offset" {
-                        Self::TextUnderlineOffset(TextUnderlineOffset(get_args(args)?))
-                    } else {
-                        return Err(WarningType::InvalidArg(
-                            args.into(),
-                            "Text Underline Offset".into(),
-                            vec!["offset"],
-                        ));
-                    }
-                } else {
-                    Self::TextDecoration(TextDecoration::Underline)
-                }
-            }
-            "indent" => Self::TextIndent(TextIndent::new(get_class_name(value), get_args(value)?)),
-            "align" => Self::VerticalAlign(VerticalAlign::new(get_args(value)?)?),
-            "whitespace" => Self::Whitespace(Whitespace::new(get_args(value)?)?),
-            "break" => Self::WordBreak(WordBreak::new(get_args(value)?)?),
-            "content" => Self::Content(Content(get_args(value)?)),
-            _ => {
-                if let Some(font_smoothing) = FontSmoothing::new(value) {
-                    Self::FontSmoothing(font_smoothing)
-                } else if let Some(font_style) = FontStyle::new(value) {
-                    Self::FontStyle(font_style)
-                } else if let Some(font_variant_numeric) = FontVariantNumeric::new(value) {
-                    Self::FontVariantNumeric(font_variant_numeric)
-                } else if let Some(text_decoration) = TextDecoration::new(value) {
-                    Self::TextDecoration(text_decoration)
-                } else if let Some(text_transform) = TextTransform::new(value) {
-                    Self::TextTransform(text_transform)
-                } else if let Some(text_overflow) = TextOverflow::new(value) {
-                    Self::TextOverflow(text_overflow)
-                } else {
-                    return Ok(None);
-                }
-            }
-        };
+pub fn typography<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, Typography<'a>> {
+    let typography = &config.typography;
 
-        Ok(Some(typography))
-    }
+    alt((
+        preceded(
+            tag("font-"),
+            alt((
+                map(|i| font_family(i, config), Typography::FontFamily),
+                map(|i| font_weight(i, config), Typography::FontWeight),
+            )),
+        ),
+        preceded(
+            tag("text-"),
+            alt((
+                map(text_align, Typography::TextAlign),
+                map(text_overflow, Typography::TextOverflow),
+                map(|i| font_size(i, config), Typography::FontSize),
+                map(|i| text_color(i, config), Typography::TextColor),
+            )),
+        ),
+        map(
+            neg_keyword_value("tracking", typography.get_letter_spacing()),
+            |v| Typography::LetterSpacing(LetterSpacing(v)),
+        ),
+        preceded(
+            tag("leading-"),
+            map(arbitrary_hashmap_value(typography.get_line_height()), |v| {
+                Typography::LineHeight(LineHeight(v))
+            }),
+        ),
+        preceded(
+            tag("list-"),
+            alt((
+                map(list_style_position, Typography::ListStylePosition),
+                map(
+                    arbitrary_hashmap_value(typography.get_list_style_type()),
+                    |v| Typography::LineStyleType(LineStyleType(v)),
+                ),
+            )),
+        ),
+        preceded(
+            tag("decoration-"),
+            alt((
+                map(text_decoration_style, Typography::TextDecorationStyle),
+                map(
+                    |i| text_decoration_thickness(i, config),
+                    Typography::TextDecorationThickness,
+                ),
+                map(
+                    |i| text_decoration_color(i, config),
+                    Typography::TextDecorationColor,
+                ),
+            )),
+        ),
+        preceded(
+            tag("underline-offset-"),
+            map(
+                arbitrary_hashmap_value(typography.get_text_underline_offset()),
+                |v| Typography::TextUnderlineOffset(TextUnderlineOffset(v)),
+            ),
+        ),
+        map(
+            neg_keyword_value("indent", typography.get_text_indent()),
+            |v| Typography::TextIndent(TextIndent(v)),
+        ),
+        preceded(
+            tag("align-"),
+            map(vertical_align, Typography::VerticalAlign),
+        ),
+        preceded(tag("whitespace-"), map(whitespace, Typography::Whitespace)),
+        preceded(tag("break-"), map(word_break, Typography::WordBreak)),
+        preceded(
+            tag("content-"),
+            map(arbitrary_hashmap_value(typography.get_content()), |v| {
+                Typography::Content(Content(v))
+            }),
+        ),
+        map(font_smoothing, Typography::FontSmoothing),
+        map(font_style, Typography::FontStyle),
+        map(font_variant_numeric, Typography::FontVariantNumeric),
+        map(text_decoration, Typography::TextDecoration),
+        map(text_transform, Typography::TextTransform),
+        map(truncate, Typography::TextOverflow),
+    ))(input)
+}
 
-    pub fn to_decl(self) -> Result<Decl, WarningType> {
+impl<'a> IntoDeclaration for Typography<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
         match self {
             Self::FontFamily(t) => t.to_decl(),
             Self::FontSize(t) => t.to_decl(),
-            Self::FontSmoothing(t) => Ok(t.to_decl()),
-            Self::FontStyle(t) => Ok(t.to_decl()),
+            Self::FontSmoothing(t) => t.to_decl(),
+            Self::FontStyle(t) => t.to_decl(),
             Self::FontWeight(t) => t.to_decl(),
-            Self::FontVariantNumeric(t) => Ok(t.to_decl()),
+            Self::FontVariantNumeric(t) => t.to_decl(),
             Self::LetterSpacing(t) => t.to_decl(),
             Self::LineHeight(t) => t.to_decl(),
             Self::LineStyleType(t) => t.to_decl(),
-            Self::ListStylePosition(t) => Ok(t.to_decl()),
-            Self::TextAlign(t) => Ok(t.to_decl()),
+            Self::ListStylePosition(t) => t.to_decl(),
+            Self::TextAlign(t) => t.to_decl(),
             Self::TextColor(t) => t.to_decl(),
-            Self::TextDecoration(t) => Ok(t.to_decl()),
+            Self::TextDecoration(t) => t.to_decl(),
             Self::TextDecorationColor(t) => t.to_decl(),
-            Self::TextDecorationStyle(t) => Ok(t.to_decl()),
+            Self::TextDecorationStyle(t) => t.to_decl(),
             Self::TextDecorationThickness(t) => t.to_decl(),
             Self::TextUnderlineOffset(t) => t.to_decl(),
-            Self::TextTransform(t) => Ok(t.to_decl()),
-            Self::TextOverflow(t) => Ok(t.to_decl()),
+            Self::TextTransform(t) => t.to_decl(),
+            Self::TextOverflow(t) => t.to_decl(),
             Self::TextIndent(t) => t.to_decl(),
             Self::VerticalAlign(t) => t.to_decl(),
-            Self::Whitespace(t) => Ok(t.to_decl()),
-            Self::WordBreak(t) => Ok(t.to_decl()),
+            Self::Whitespace(t) => t.to_decl(),
+            Self::WordBreak(t) => t.to_decl(),
             Self::Content(t) => t.to_decl(),
         }
     }
 }
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("font-family")]
+pub struct FontFamily<'a>(pub &'a str);
+
+// Arbitrary font families are quoted, like `font-['Open_Sans']`, anything else is a weight
+fn font_family<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, FontFamily<'a>> {
+    map(
+        alt((
+            verify(arbitrary, |v: &str| v.starts_with(['"', '\''])),
+            hashmap_value(config.typography.get_font_family()),
+        )),
+        FontFamily,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum FontSize<'a> {
+    Size(&'a str, &'a str),
+    Arbitrary(&'a str),
+}
+
+fn font_size<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, FontSize<'a>> {
+    alt((
+        map(verify(arbitrary, |v: &str| !is_color(v)), |v| {
+            FontSize::Arbitrary(strip_hint(v, "length"))
+        }),
+        map_opt(rest, |v: &str| {
+            config
+                .typography
+                .get_font_size()
+                .get(v)
+                .map(|(size, line_height)| FontSize::Size(size, line_height))
+        }),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for FontSize<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Size(size, line_height) => vec![
+                Declaration::new("font-size", size),
+                Declaration::new("line-height", line_height),
+            ],
+            Self::Arbitrary(size) => vec![Declaration::new("font-size", size)],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(font_smoothing)]
+pub enum FontSmoothing {
+    #[tag("antialiased")]
+    Antialiased,
+    #[tag("subpixel-antialiased")]
+    SubpixelAntialiased,
+}
+
+impl IntoDeclaration for FontSmoothing {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Antialiased => vec![
+                Declaration::new("-webkit-font-smoothing", "antialiased"),
+                Declaration::new("-moz-osx-font-smoothing", "grayscale"),
+            ],
+            Self::SubpixelAntialiased => vec![
+                Declaration::new("-webkit-font-smoothing", "auto"),
+                Declaration::new("-moz-osx-font-smoothing", "auto"),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(font_style)]
+#[decl("font-style")]
+pub enum FontStyle {
+    #[tag("italic")]
+    Italic,
+    #[tag("not-italic")]
+    #[decl("normal")]
+    NonItalic,
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("font-weight")]
+pub struct FontWeight<'a>(pub &'a str);
+
+fn font_weight<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, FontWeight<'a>> {
+    map(
+        arbitrary_hashmap_value(config.typography.get_font_weight()),
+        FontWeight,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(font_variant_numeric)]
+pub enum FontVariantNumeric {
+    #[tag("normal-nums")]
+    NormalNums,
+    #[tag("ordinal")]
+    Ordinal,
+    #[tag("slashed-zero")]
+    SlashedZero,
+    #[tag("lining-nums")]
+    LiningNums,
+    #[tag("oldstyle-nums")]
+    OldstyleNums,
+    #[tag("proportional-nums")]
+    ProportialNums,
+    #[tag("tabular-nums")]
+    TabularNums,
+    #[tag("diagonal-fractions")]
+    DiagonalFractions,
+    #[tag("stacked-fractions")]
+    StackedFractions,
+}
+
+impl IntoDeclaration for FontVariantNumeric {
+    fn to_decl(self) -> Vec<Declaration> {
+        let value = match self {
+            Self::NormalNums => return vec![Declaration::new("font-variant-numeric", "normal")],
+            Self::Ordinal => ("--tw-ordinal", "ordinal"),
+            Self::SlashedZero => ("--tw-slashed-zero", "slashed-zero"),
+            Self::LiningNums => ("--tw-numeric-figure", "lining-nums"),
+            Self::OldstyleNums => ("--tw-numeric-figure", "oldstyle-nums"),
+            Self::ProportialNums => ("--tw-numeric-spacing", "proportional-nums"),
+            Self::TabularNums => ("--tw-numeric-spacing", "tabular-nums"),
+            Self::DiagonalFractions => ("--tw-numeric-fraction", "diagonal-fractions"),
+            Self::StackedFractions => ("--tw-numeric-fraction", "stacked-fractions"),
+        };
+
+        vec![
+            value.into(),
+            Declaration::new(
+                "font-variant-numeric",
+                "var(--tw-ordinal) var(--tw-slashed-zero)
+        var(--tw-numeric-figure) var(--tw-numeric-spacing)
+        var(--tw-numeric-fraction)",
+            ),
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct LetterSpacing(pub String);
+
+impl IntoDeclaration for LetterSpacing {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("letter-spacing", self.0)]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("line-height")]
+pub struct LineHeight<'a>(pub &'a str);
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("list-style-type")]
+pub struct LineStyleType<'a>(pub &'a str);
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(list_style_position)]
+#[decl("list-style-position")]
+pub enum ListStylePosition {
+    #[tag("inside")]
+    Inside,
+    #[tag("outside")]
+    Outside,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(text_align)]
+#[decl("text-align")]
+pub enum TextAlign {
+    #[tag("left")]
+    Left,
+    #[tag("center")]
+    Center,
+    #[tag("right")]
+    Right,
+    #[tag("justify")]
+    Justify,
+    #[tag("start")]
+    Start,
+    #[tag("end")]
+    End,
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct TextColor<'a>(pub Color<'a>);
+
+fn text_color<'a>(input: &'a str, config: &'a Config) -> IResult<&'a str, TextColor<'a>> {
+    map(color(config.typography.get_text_color()), TextColor)(input)
+}
+
+impl<'a> IntoDeclaration for TextColor<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        let mut decls: Vec<Declaration> = self
+            .0
+            .opacity_decl("--tw-text-opacity")
+            .into_iter()
+            .collect();
+        decls.push(Declaration::new(
+            "color",
+            self.0.value(Some("--tw-text-opacity")),
+        ));
+        decls
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(text_decoration)]
+pub enum TextDecoration {
+    #[tag("underline")]
+    Underline,
+    #[tag("overline")]
+    Overline,
+    #[tag("line-through")]
+    LineThrough,
+    #[tag("no-underline")]
+    NoUnderline,
+}
+
+impl IntoDeclaration for TextDecoration {
+    fn to_decl(self) -> Vec<Declaration> {
+        let value = match self {
+            Self::Underline => "underline",
+            Self::Overline => "overline",
+            Self::LineThrough => "line-through",
+            Self::NoUnderline => "none",
+        };
+
+        vec![
+            Declaration::new("-webkit-text-decoration-line", value),
+            Declaration::new("text-decoration-line", value),
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct TextDecorationColor<'a>(pub Color<'a>);
+
+fn text_decoration_color<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, TextDecorationColor<'a>> {
+    map(
+        color(config.typography.get_text_decoration_color()),
+        TextDecorationColor,
+    )(input)
+}
+
+impl<'a> IntoDeclaration for TextDecorationColor<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        let value = self.0.value(None);
+
+        vec![
+            Declaration::new("-webkit-text-decoration-color", &value),
+            Declaration::new("text-decoration-color", value),
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(text_decoration_style)]
+pub enum TextDecorationStyle {
+    #[tag("solid")]
+    Solid,
+    #[tag("double")]
+    Double,
+    #[tag("dotted")]
+    Dotted,
+    #[tag("dashed")]
+    Dashed,
+    #[tag("wavy")]
+    Wavy,
+}
+
+impl IntoDeclaration for TextDecorationStyle {
+    fn to_decl(self) -> Vec<Declaration> {
+        let value = match self {
+            Self::Solid => "solid",
+            Self::Double => "double",
+            Self::Dotted => "dotted",
+            Self::Dashed => "dashed",
+            Self::Wavy => "wavy",
+        };
+
+        vec![
+            Declaration::new("-webkit-text-decoration-style", value),
+            Declaration::new("text-decoration-style", value),
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("text-decoration-thickness")]
+pub struct TextDecorationThickness<'a>(pub &'a str);
+
+fn text_decoration_thickness<'a>(
+    input: &'a str,
+    config: &'a Config,
+) -> IResult<&'a str, TextDecorationThickness<'a>> {
+    map(
+        alt((
+            map(verify(arbitrary, |v: &str| !is_color(v)), |v| {
+                strip_hint(v, "length")
+            }),
+            hashmap_value(config.typography.get_text_decoration_thickness()),
+        )),
+        TextDecorationThickness,
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Hash, IntoDeclaration)]
+#[decl("text-underline-offset")]
+pub struct TextUnderlineOffset<'a>(pub &'a str);
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(text_transform)]
+#[decl("text-transform")]
+pub enum TextTransform {
+    #[tag("uppercase")]
+    Uppercase,
+    #[tag("lowercase")]
+    Lowercase,
+    #[tag("capitalize")]
+    Capitalize,
+    #[tag("normal-case")]
+    #[decl("none")]
+    NormalCase,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(text_overflow)]
+pub enum TextOverflow {
+    #[tag("truncate")]
+    Truncate,
+    #[tag("ellipsis")]
+    TextEllipsis,
+    #[tag("clip")]
+    TextClip,
+}
+
+// `truncate` is the only text overflow class without the `text-` prefix
+fn truncate(input: &str) -> IResult<&str, TextOverflow> {
+    verify(text_overflow, |t| *t == TextOverflow::Truncate)(input)
+}
+
+impl IntoDeclaration for TextOverflow {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Truncate => vec![
+                Declaration::new("overflow", "hidden"),
+                Declaration::new("text-overflow", "ellipsis"),
+                Declaration::new("white-space", "nowrap"),
+            ],
+            Self::TextEllipsis => vec![Declaration::new("text-overflow", "ellipsis")],
+            Self::TextClip => vec![Declaration::new("text-overflow", "clip")],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct TextIndent(pub String);
+
+impl IntoDeclaration for TextIndent {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![Declaration::new("text-indent", self.0)]
+    }
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub enum VerticalAlign<'a> {
+    Keyword(VerticalAlignKeyword),
+    Arbitrary(&'a str),
+}
+
+fn vertical_align(input: &str) -> IResult<&str, VerticalAlign<'_>> {
+    alt((
+        map(vertical_align_keyword, VerticalAlign::Keyword),
+        map(arbitrary, VerticalAlign::Arbitrary),
+    ))(input)
+}
+
+impl<'a> IntoDeclaration for VerticalAlign<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Keyword(k) => k.to_decl(),
+            Self::Arbitrary(a) => vec![Declaration::new("vertical-align", a)],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(vertical_align_keyword)]
+#[decl("vertical-align")]
+pub enum VerticalAlignKeyword {
+    #[tag("baseline")]
+    Baseline,
+    #[tag("top")]
+    Top,
+    #[tag("middle")]
+    Middle,
+    #[tag("bottom")]
+    Bottom,
+    #[tag("text-top")]
+    TextTop,
+    #[tag("text-bottom")]
+    TextBottom,
+    #[tag("sub")]
+    Sub,
+    #[tag("super")]
+    Super,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser, IntoDeclaration)]
+#[name(whitespace)]
+#[decl("white-space")]
+pub enum Whitespace {
+    #[tag("normal")]
+    Normal,
+    #[tag("nowrap")]
+    NoWrap,
+    #[tag("pre")]
+    Pre,
+    #[tag("pre-line")]
+    PreLine,
+    #[tag("pre-wrap")]
+    PreWrap,
+}
+
+#[derive(Debug, PartialEq, Hash, EnumParser)]
+#[name(word_break)]
+pub enum WordBreak {
+    #[tag("normal")]
+    Normal,
+    #[tag("words")]
+    Words,
+    #[tag("all")]
+    All,
+    #[tag("keep")]
+    Keep,
+}
+
+impl IntoDeclaration for WordBreak {
+    fn to_decl(self) -> Vec<Declaration> {
+        match self {
+            Self::Normal => {
+                vec![
+                    Declaration::new("overflow-wrap", "normal"),
+                    Declaration::new("word-break", "normal"),
+                ]
+            }
+            Self::Words => vec![Declaration::new("overflow-wrap", "break-word")],
+            Self::All => vec![Declaration::new("word-break", "break-all")],
+            Self::Keep => vec![Declaration::new("word-break", "keep-all")],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash)]
+pub struct Content<'a>(pub &'a str);
+
+impl<'a> IntoDeclaration for Content<'a> {
+    fn to_decl(self) -> Vec<Declaration> {
+        vec![
+            Declaration::new("--tw-content", self.0),
+            Declaration::new("content", "var(--tw-content)"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            typography("text-center", &Config::default()),
+            Ok(("", Typography::TextAlign(TextAlign::Center)))
+        );
+        assert_eq!(
+            typography("text-[14px]", &Config::default()),
+            Ok(("", Typography::FontSize(FontSize::Arbitrary("14px"))))
+        );
+        assert_eq!(
+            typography("text-[#fff]", &Config::default()),
+            Ok(("", Typography::TextColor(TextColor(Color::new("#fff")))))
+        );
+        assert_eq!(
+            typography("text-[length:2rem]", &Config::default()),
+            Ok(("", Typography::FontSize(FontSize::Arbitrary("2rem"))))
+        );
+        assert_eq!(
+            typography("text-[color:var(--x)]", &Config::default()),
+            Ok(("", Typography::TextColor(TextColor(Color::new("var(--x)")))))
+        );
+        assert_eq!(
+            typography("decoration-[length:3px]", &Config::default()),
+            Ok((
+                "",
+                Typography::TextDecorationThickness(TextDecorationThickness("3px"))
+            ))
+        );
+        assert_eq!(
+            typography("-tracking-[1px]", &Config::default()),
+            Ok(("", Typography::LetterSpacing(LetterSpacing("-1px".into()))))
+        );
+    }
+}
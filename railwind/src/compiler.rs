@@ -0,0 +1,506 @@
+use indexmap::IndexMap;
+use std::fs::read_to_string;
+
+use crate::config::Config;
+use crate::css::{self, PrintMode, Printer, SourceMap, Stylesheet};
+use crate::warning::{Position, Warning, WarningType};
+use crate::PREFLIGHT;
+use crate::{collect_with_option, generate_nodes, CollectionOptions, Source, SourceOptions};
+
+/// Compiles classes to CSS with a reusable `Config`.
+///
+/// The theme tables in the config are only deserialized the first time a class needs them,
+/// so keeping one `Compiler` around makes every compile after the first one cheaper.
+#[derive(Default)]
+pub struct Compiler {
+    config: Config,
+    include_preflight: bool,
+    printer: Printer,
+}
+
+impl Compiler {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            include_preflight: false,
+            printer: Printer::default(),
+        }
+    }
+
+    /// Prepends the Tailwind preflight to every compiled stylesheet
+    pub fn include_preflight(mut self, include_preflight: bool) -> Self {
+        self.include_preflight = include_preflight;
+        self
+    }
+
+    /// Sets the printer every compiled stylesheet is printed with
+    pub fn printer(mut self, printer: Printer) -> Self {
+        self.printer = printer;
+        self
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut Config {
+        &mut self.config
+    }
+
+    pub fn compile(&self, source: Source, warnings: &mut Vec<Warning>) -> String {
+        self.finish(&self.stylesheet(source, warnings))
+    }
+
+    pub fn compile_str(
+        &self,
+        str: &str,
+        option: &CollectionOptions,
+        warnings: &mut Vec<Warning>,
+    ) -> String {
+        self.finish(&self.stylesheet_from_str(str, option, warnings))
+    }
+
+    pub fn compile_files(&self, sources: &[SourceOptions], warnings: &mut Vec<Warning>) -> String {
+        self.finish(&self.stylesheet_from_files(sources, warnings))
+    }
+
+    /// Compiles classes that were already collected, like `["px-5", "hover:underline"]`.
+    /// Warnings report each class as if they were written on one line, separated by spaces.
+    pub fn compile_classes<'a>(
+        &self,
+        classes: impl IntoIterator<Item = &'a str>,
+        warnings: &mut Vec<Warning>,
+    ) -> String {
+        self.finish(&self.stylesheet_from_classes(classes, warnings))
+    }
+
+    /// Generates the stylesheet for a source without printing it,
+    /// so it can be inspected or changed first. The preflight is not included.
+    pub fn stylesheet(&self, source: Source, warnings: &mut Vec<Warning>) -> Stylesheet {
+        match source {
+            Source::File(opt) => self.stylesheet_from_files(&[opt], warnings),
+            Source::Files(opts) => self.stylesheet_from_files(&opts, warnings),
+            Source::String(str, opt) => self.stylesheet_from_str(&str, &opt, warnings),
+        }
+    }
+
+    pub fn stylesheet_from_str(
+        &self,
+        str: &str,
+        option: &CollectionOptions,
+        warnings: &mut Vec<Warning>,
+    ) -> Stylesheet {
+        let raw_classes = collect_with_option(str, option);
+
+        Stylesheet::new(generate_nodes(raw_classes, &self.config, warnings))
+    }
+
+    pub fn stylesheet_from_files(
+        &self,
+        sources: &[SourceOptions],
+        warnings: &mut Vec<Warning>,
+    ) -> Stylesheet {
+        let mut raw_string_classes: IndexMap<String, Position> = IndexMap::new();
+
+        for opt in sources {
+            let file_string = match read_to_string(opt.input) {
+                Ok(file_string) => file_string,
+                Err(e) => {
+                    let position = Position::new(opt.input.display(), 0, 0);
+                    warnings.push(Warning::new(
+                        "",
+                        &position,
+                        WarningType::FileNotRead(e.to_string()),
+                    ));
+                    continue;
+                }
+            };
+
+            for (raw_str, position) in collect_with_option(&file_string, &opt.option) {
+                raw_string_classes
+                    .entry(raw_str.to_string())
+                    .or_insert_with(|| {
+                        Position::new(opt.input.display(), position.line(), position.column())
+                    });
+            }
+        }
+
+        let mut raw_classes: IndexMap<&str, Position> = IndexMap::new();
+        for (c, p) in &raw_string_classes {
+            raw_classes.insert(c, p.clone());
+        }
+
+        Stylesheet::new(generate_nodes(raw_classes, &self.config, warnings))
+    }
+
+    pub fn stylesheet_from_classes<'a>(
+        &self,
+        classes: impl IntoIterator<Item = &'a str>,
+        warnings: &mut Vec<Warning>,
+    ) -> Stylesheet {
+        let mut raw_classes = IndexMap::new();
+        let mut column = 1;
+
+        for class in classes {
+            raw_classes
+                .entry(class)
+                .or_insert(Position::new("", 1, column));
+            column += class.len() + 1;
+        }
+
+        Stylesheet::new(generate_nodes(raw_classes, &self.config, warnings))
+    }
+
+    /// Prints a stylesheet, with the preflight in front of it if it's enabled
+    pub fn finish(&self, stylesheet: &Stylesheet) -> String {
+        self.finish_with_source_map(stylesheet).0
+    }
+
+    /// Prints a stylesheet like `finish`, together with a source map that points every rule
+    /// to where its class first appeared. Write the map next to the CSS and link it with
+    /// `css::url_comment`, or embed it with `SourceMap::to_inline_comment`. The sources are
+    /// the paths the files were read from, `SourceMap::sources_relative_to` makes them
+    /// relative to the directory of the CSS.
+    pub fn finish_with_source_map(&self, stylesheet: &Stylesheet) -> (String, SourceMap) {
+        if self.printer.mode() == PrintMode::Minified {
+            let mut nodes = if self.include_preflight {
+                css::parse_rules(&PREFLIGHT)
+            } else {
+                vec![]
+            };
+            nodes.extend(stylesheet.nodes.iter().cloned());
+
+            return self.printer.print_with_source_map(&Stylesheet::new(nodes));
+        }
+
+        let (printed, mut source_map) = self.printer.print_with_source_map(stylesheet);
+
+        let mut css = if self.include_preflight {
+            source_map.offset_lines(PREFLIGHT.matches('\n').count());
+            PREFLIGHT.to_string()
+        } else {
+            String::new()
+        };
+
+        css.push_str(&printed);
+        css.push('\n');
+        (css, source_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::{Declaration, Node};
+    use crate::{UtilityPlugin, Variant, VariantPlugin, Variants};
+
+    #[test]
+    fn test_compile_classes() {
+        let compiler = Compiler::default();
+        let mut warnings = vec![];
+
+        let css = compiler.compile_classes(["px-5", "text-center"], &mut warnings);
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            css,
+            ".px-5 {\n    padding-left: 1.25rem;\n    padding-right: 1.25rem;\n}\n\n.text-center {\n    text-align: center;\n}\n"
+        );
+        assert_eq!(
+            compiler.compile_str(
+                "px-5 text-center",
+                &CollectionOptions::String,
+                &mut warnings
+            ),
+            css
+        );
+    }
+
+    #[test]
+    fn test_source_order_does_not_matter() {
+        let compiler = Compiler::default().printer(Printer::minified());
+        let mut warnings = vec![];
+
+        let css = compiler.compile_classes(["md:p-8", "hover:p-1", "px-2", "p-4"], &mut warnings);
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            css,
+            r#".p-4{padding:1rem}.px-2{padding-left:.5rem;padding-right:.5rem}.hover\:p-1:hover{padding:.25rem}@media (min-width:768px){.md\:p-8{padding:2rem}}"#
+        );
+        assert_eq!(
+            compiler.compile_classes(["p-4", "px-2", "hover:p-1", "md:p-8"], &mut warnings),
+            css
+        );
+    }
+
+    #[test]
+    fn test_media_queries_are_merged() {
+        let compiler = Compiler::default().printer(Printer::minified());
+        let mut warnings = vec![];
+
+        let css = compiler.compile_classes(
+            ["md:p-8", "sm:flex", "md:dark:flex", "md:px-2"],
+            &mut warnings,
+        );
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            css,
+            r#"@media (min-width:640px){.sm\:flex{display:flex}}@media (min-width:768px){.md\:p-8{padding:2rem}.md\:px-2{padding-left:.5rem;padding-right:.5rem}@media (prefers-color-scheme:dark){.md\:dark\:flex{display:flex}}}"#
+        );
+    }
+
+    #[test]
+    fn test_source_map() {
+        let path = std::env::temp_dir().join("railwind_test_source_map.html");
+        std::fs::write(
+            &path,
+            "<div class=\"px-5\">\n  <p class=\"md:flex px-5 text-center\"></p>\n</div>",
+        )
+        .unwrap();
+
+        let compiler = Compiler::default();
+        let mut warnings = vec![];
+        let stylesheet = compiler.stylesheet_from_files(
+            &[SourceOptions {
+                input: &path,
+                option: CollectionOptions::Html,
+            }],
+            &mut warnings,
+        );
+        let (_, source_map) = compiler.finish_with_source_map(&stylesheet);
+
+        let file = path.display().to_string();
+        let mapping = |generated_line, generated_column, line, column| css::Mapping {
+            generated_line,
+            generated_column,
+            source: Position::new(&file, line, column),
+        };
+
+        assert_eq!(
+            source_map.mappings(),
+            &[
+                mapping(0, 0, 1, 13),
+                mapping(5, 0, 2, 26),
+                mapping(10, 4, 2, 13)
+            ]
+        );
+
+        let (_, source_map) = compiler
+            .include_preflight(true)
+            .finish_with_source_map(&stylesheet);
+
+        assert_eq!(
+            source_map.mappings()[0],
+            mapping(PREFLIGHT.matches('\n').count(), 0, 1, 13)
+        );
+    }
+
+    #[test]
+    fn test_missing_file_is_skipped() {
+        let path = std::env::temp_dir().join("railwind_test_missing_file.html");
+        std::fs::write(&path, r#"<div class="px-5"></div>"#).unwrap();
+        let missing = std::env::temp_dir().join("railwind_test_does_not_exist.html");
+
+        let compiler = Compiler::default().printer(Printer::minified());
+        let mut warnings = vec![];
+        let css = compiler.compile_files(
+            &[
+                SourceOptions {
+                    input: &missing,
+                    option: CollectionOptions::Html,
+                },
+                SourceOptions {
+                    input: &path,
+                    option: CollectionOptions::Html,
+                },
+            ],
+            &mut warnings,
+        );
+
+        assert_eq!(css, ".px-5{padding-left:1.25rem;padding-right:1.25rem}");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0]
+            .to_string()
+            .contains(&format!("Could not read file '{}'", missing.display())));
+    }
+
+    struct Htmx;
+
+    impl VariantPlugin for Htmx {
+        fn variant(&self, name: &str, _: &Variants) -> Option<Variant> {
+            let state = name.strip_prefix("htmx-")?;
+            Some(Variant::selector(format!("&.htmx-{}", state)))
+        }
+    }
+
+    #[test]
+    fn test_custom_variants() {
+        let mut compiler = Compiler::default().printer(Printer::minified());
+        let variants = &mut compiler.config_mut().variants;
+        variants.add("hocus", Variant::selector("&:is(:hover, :focus)"));
+        variants.add(
+            "supports-grid",
+            Variant::at_rule("supports", "(display: grid)"),
+        );
+        variants.add_plugin(Htmx);
+
+        let mut warnings = vec![];
+        let css = compiler.compile_classes(
+            ["group-hocus:p-1", "supports-grid:grid", "htmx-request:p-2"],
+            &mut warnings,
+        );
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            css,
+            r#".group:is(:hover,:focus) .group-hocus\:p-1{padding:.25rem}@supports (display:grid){.supports-grid\:grid{display:grid}}.htmx-request\:p-2.htmx-request{padding:.5rem}"#
+        );
+    }
+
+    #[test]
+    fn test_compile_classes_warning_position() {
+        let compiler = Compiler::default();
+        let mut warnings = vec![];
+
+        compiler.compile_classes(["px-5", "px-nope", "nth-[3x]:p-3"], &mut warnings);
+
+        assert_eq!(
+            warnings,
+            vec![
+                Warning::new(
+                    "px-nope",
+                    &Position::new("", 1, 6),
+                    crate::warning::WarningType::ClassNotFound
+                ),
+                Warning::new(
+                    "nth-[3x]:p-3",
+                    &Position::new("", 1, 14),
+                    crate::warning::WarningType::InvalidStateArg(
+                        "nth-[3x]".into(),
+                        "3x".into(),
+                        "an An+B expression, like '3', '2n+1' or 'odd'".into()
+                    )
+                )
+            ]
+        );
+    }
+
+    struct Elevation;
+
+    impl UtilityPlugin for Elevation {
+        fn parse(&self, class: &str, _: &Config) -> Result<Vec<Declaration>, WarningType> {
+            let level = class
+                .strip_prefix("elevation-")
+                .ok_or(WarningType::ClassNotFound)?;
+
+            let shadow = match level {
+                "1" => "0 1px 2px rgb(0 0 0 / 0.2)",
+                "2" => "0 2px 4px rgb(0 0 0 / 0.2)",
+                _ => return Err(WarningType::ValueNotFound(level.to_string())),
+            };
+
+            Ok(vec![Declaration::new("box-shadow", shadow)])
+        }
+    }
+
+    #[test]
+    fn test_utility_plugins() {
+        let mut compiler = Compiler::default().printer(Printer::minified());
+        compiler.config_mut().utilities.push(Box::new(Elevation));
+
+        let mut warnings = vec![];
+        let css = compiler.compile_classes(
+            [
+                "md:elevation-1",
+                "hover:elevation-2",
+                "elevation-1",
+                "p-4",
+                "elevation-9",
+            ],
+            &mut warnings,
+        );
+
+        assert_eq!(
+            css,
+            r#".p-4{padding:1rem}.elevation-1{box-shadow:0 1px 2px rgb(0 0 0 / .2)}.hover\:elevation-2:hover{box-shadow:0 2px 4px rgb(0 0 0 / .2)}@media (min-width:768px){.md\:elevation-1{box-shadow:0 1px 2px rgb(0 0 0 / .2)}}"#
+        );
+        assert_eq!(
+            warnings,
+            vec![Warning::new(
+                "elevation-9",
+                &Position::new("", 1, 50),
+                WarningType::ValueNotFound("9".to_string())
+            )]
+        );
+    }
+
+    #[test]
+    fn test_stylesheet_can_be_changed_before_printing() {
+        let compiler = Compiler::default();
+        let mut warnings = vec![];
+
+        let mut stylesheet = compiler.stylesheet_from_classes(["px-5"], &mut warnings);
+        if let Node::Rule(rule) = &mut stylesheet.nodes[0] {
+            for decl in &mut rule.declarations {
+                decl.important = true;
+            }
+        }
+
+        assert_eq!(
+            compiler.finish(&stylesheet),
+            ".px-5 {\n    padding-left: 1.25rem !important;\n    padding-right: 1.25rem !important;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_compiler_can_move_to_another_thread() {
+        let mut compiler = Compiler::default();
+        compiler.config_mut().utilities.push(Box::new(Elevation));
+        compiler.config_mut().variants.add_plugin(Htmx);
+
+        let css = std::thread::spawn(move || {
+            compiler.compile_classes(["htmx-request:elevation-1"], &mut vec![])
+        })
+        .join()
+        .unwrap();
+
+        assert!(css.contains("box-shadow"));
+    }
+
+    #[test]
+    fn test_minified() {
+        let compiler = Compiler::default().printer(Printer::minified());
+        let mut warnings = vec![];
+
+        assert_eq!(
+            compiler.compile_classes(["px-0.5", "hover:fill-[#ffffff]"], &mut warnings),
+            r".px-0\.5{padding-left:.125rem;padding-right:.125rem}.hover\:fill-\[\#ffffff\]:hover{fill:#fff}"
+        );
+
+        let with_preflight = Compiler::default()
+            .printer(Printer::minified())
+            .include_preflight(true)
+            .compile_classes(["px-5"], &mut warnings);
+
+        assert!(with_preflight.starts_with("*,::before,::after{box-sizing:border-box;"));
+        assert!(!with_preflight.contains('\n'));
+    }
+
+    #[test]
+    fn test_customised_config() {
+        let mut compiler = Compiler::default();
+        compiler
+            .config_mut()
+            .backgrounds
+            .get_mut_color()
+            .insert("brand".into(), "#ff0000".into());
+
+        let mut warnings = vec![];
+        let css = compiler.compile_classes(["bg-brand"], &mut warnings);
+
+        assert!(warnings.is_empty());
+        assert!(css.contains("background-color: rgb(255 0 0 / var(--tw-bg-opacity))"));
+    }
+}
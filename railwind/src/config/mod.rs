@@ -3,7 +3,15 @@ use std::collections::HashMap;
 
 use macro_derive::GetOnceCell;
 
-mod layout;
+use crate::class::UtilityPlugin;
+use crate::modifiers::Variants;
+
+mod theme;
+
+pub use theme::{Tables, Theme, ThemeCategory, ThemeExtension, UnknownTable};
+
+/// The selector and declarations of every block in a `@keyframes` rule
+pub type Keyframes = Vec<(String, Vec<(String, String)>)>;
 
 #[derive(Default)]
 pub struct Config {
@@ -13,175 +21,332 @@ pub struct Config {
     pub effects: EffectsConfig,
     pub filters: FiltersConfig,
     pub flexbox_grid: FlexboxGridConfig,
+    pub interactivity: InteractivityConfig,
+    pub layout: LayoutConfig,
+    pub sizing: SizingConfig,
+    pub svg: SvgConfig,
+    pub tables: TablesConfig,
+    pub transforms: TransformsConfig,
+    pub transitions_animation: TransitionsAnimationConfig,
+    pub typography: TypographyConfig,
+    pub variants: Variants,
+    pub utilities: Vec<Box<dyn UtilityPlugin>>,
 }
 
 #[derive(GetOnceCell, Default)]
 pub struct BackgroundsConfig {
     #[config_path("colors.ron")]
-    color: OnceCell<HashMap<&'static str, &'static str>>,
+    color: OnceCell<HashMap<String, String>>,
 
     #[config_path("backgrounds/background_position.ron")]
-    position: OnceCell<HashMap<&'static str, &'static str>>,
+    position: OnceCell<HashMap<String, String>>,
 
     #[config_path("backgrounds/background_size.ron")]
-    size: OnceCell<HashMap<&'static str, &'static str>>,
+    size: OnceCell<HashMap<String, String>>,
 
     #[config_path("backgrounds/background_image.ron")]
-    image: OnceCell<HashMap<&'static str, &'static str>>,
+    image: OnceCell<HashMap<String, String>>,
 
     #[config_path("colors.ron")]
-    gradient_color_stops: OnceCell<HashMap<&'static str, &'static str>>,
+    gradient_color_stops: OnceCell<HashMap<String, String>>,
 }
 
 #[derive(GetOnceCell, Default)]
 pub struct SpacingConfig {
     #[config_path("spacing/padding.ron")]
-    padding: OnceCell<HashMap<&'static str, &'static str>>,
+    padding: OnceCell<HashMap<String, String>>,
 
     #[config_path("spacing/margin.ron")]
-    margin: OnceCell<HashMap<&'static str, &'static str>>,
+    margin: OnceCell<HashMap<String, String>>,
 
     #[config_path("spacing/space_between.ron")]
-    space_between: OnceCell<HashMap<&'static str, &'static str>>,
+    space_between: OnceCell<HashMap<String, String>>,
 }
 
 #[derive(GetOnceCell, Default)]
 pub struct BordersConfig {
     #[config_path("borders/border_radius.ron")]
-    border_radius: OnceCell<HashMap<&'static str, &'static str>>,
+    border_radius: OnceCell<HashMap<String, String>>,
 
     #[config_path("borders/border_width.ron")]
-    border_width: OnceCell<HashMap<&'static str, &'static str>>,
+    border_width: OnceCell<HashMap<String, String>>,
 
     #[config_path("colors.ron")]
-    border_color: OnceCell<HashMap<&'static str, &'static str>>,
+    border_color: OnceCell<HashMap<String, String>>,
 
     #[config_path("borders/divide_width.ron")]
-    divide_width: OnceCell<HashMap<&'static str, &'static str>>,
+    divide_width: OnceCell<HashMap<String, String>>,
 
     #[config_path("colors.ron")]
-    divide_color: OnceCell<HashMap<&'static str, &'static str>>,
+    divide_color: OnceCell<HashMap<String, String>>,
 
     #[config_path("borders/outline_width.ron")]
-    outline_width: OnceCell<HashMap<&'static str, &'static str>>,
+    outline_width: OnceCell<HashMap<String, String>>,
 
     #[config_path("colors.ron")]
-    outline_color: OnceCell<HashMap<&'static str, &'static str>>,
+    outline_color: OnceCell<HashMap<String, String>>,
 
     #[config_path("borders/border_width.ron")]
-    outline_offset: OnceCell<HashMap<&'static str, &'static str>>,
+    outline_offset: OnceCell<HashMap<String, String>>,
 
     #[config_path("borders/ring_width.ron")]
-    ring_width: OnceCell<HashMap<&'static str, &'static str>>,
+    ring_width: OnceCell<HashMap<String, String>>,
 
     #[config_path("colors.ron")]
-    ring_color: OnceCell<HashMap<&'static str, &'static str>>,
+    ring_color: OnceCell<HashMap<String, String>>,
 
     #[config_path("borders/outline_width.ron")]
-    ring_offset_width: OnceCell<HashMap<&'static str, &'static str>>,
+    ring_offset_width: OnceCell<HashMap<String, String>>,
 
     #[config_path("colors.ron")]
-    ring_offset_color: OnceCell<HashMap<&'static str, &'static str>>,
+    ring_offset_color: OnceCell<HashMap<String, String>>,
 }
 
 #[derive(GetOnceCell, Default)]
 pub struct EffectsConfig {
     #[config_path("effects/box_shadow.ron")]
-    box_shadow: OnceCell<HashMap<&'static str, &'static str>>,
+    box_shadow: OnceCell<HashMap<String, String>>,
 
     #[config_path("colors.ron")]
-    box_shadow_color: OnceCell<HashMap<&'static str, &'static str>>,
+    box_shadow_color: OnceCell<HashMap<String, String>>,
 
     #[config_path("effects/opacity.ron")]
-    opacity: OnceCell<HashMap<&'static str, &'static str>>,
+    opacity: OnceCell<HashMap<String, String>>,
 }
 
 #[derive(GetOnceCell, Default)]
 pub struct FiltersConfig {
     #[config_path("filters/blur.ron")]
-    blur: OnceCell<HashMap<&'static str, &'static str>>,
+    blur: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/brightness.ron")]
-    brightness: OnceCell<HashMap<&'static str, &'static str>>,
+    brightness: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/contrast.ron")]
-    contrast: OnceCell<HashMap<&'static str, &'static str>>,
+    contrast: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/drop_shadow.ron")]
-    drop_shadow: OnceCell<HashMap<&'static str, &'static str>>,
+    drop_shadow: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/grayscale.ron")]
-    grayscale: OnceCell<HashMap<&'static str, &'static str>>,
+    grayscale: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/hue_rotate.ron")]
-    hue_rotate: OnceCell<HashMap<&'static str, &'static str>>,
+    hue_rotate: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/invert.ron")]
-    invert: OnceCell<HashMap<&'static str, &'static str>>,
+    invert: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/saturate.ron")]
-    saturate: OnceCell<HashMap<&'static str, &'static str>>,
+    saturate: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/sepia.ron")]
-    sepia: OnceCell<HashMap<&'static str, &'static str>>,
+    sepia: OnceCell<HashMap<String, String>>,
 
     #[config_path("filters/opacity.ron")]
-    backdrop_opacity: OnceCell<HashMap<&'static str, &'static str>>,
+    backdrop_opacity: OnceCell<HashMap<String, String>>,
 }
 
 #[derive(GetOnceCell, Default)]
 pub struct FlexboxGridConfig {
     #[config_path("flexbox_grid/basis.ron")]
-    basis: OnceCell<HashMap<&'static str, &'static str>>,
+    basis: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/flex.ron")]
-    flex: OnceCell<HashMap<&'static str, &'static str>>,
+    flex: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grow.ron")]
-    grow: OnceCell<HashMap<&'static str, &'static str>>,
+    grow: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/shrink.ron")]
-    shrink: OnceCell<HashMap<&'static str, &'static str>>,
+    shrink: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/order.ron")]
-    order: OnceCell<HashMap<&'static str, &'static str>>,
+    order: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_template_columns.ron")]
-    grid_template_columns: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_template_columns: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_column_span.ron")]
-    grid_column_span: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_column_span: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_column_start_end.ron")]
-    grid_column_start: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_column_start: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_column_start_end.ron")]
-    grid_column_end: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_column_end: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_template_rows.ron")]
-    grid_template_rows: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_template_rows: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_row_span.ron")]
-    grid_row_span: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_row_span: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_row_start_end.ron")]
-    grid_row_start: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_row_start: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_row_start_end.ron")]
-    grid_row_end: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_row_end: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_auto_columns.ron")]
-    grid_auto_columns: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_auto_columns: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/grid_auto_rows.ron")]
-    grid_auto_rows: OnceCell<HashMap<&'static str, &'static str>>,
+    grid_auto_rows: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/gap.ron")]
-    gap: OnceCell<HashMap<&'static str, &'static str>>,
+    gap: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/gap.ron")]
-    gap_x: OnceCell<HashMap<&'static str, &'static str>>,
+    gap_x: OnceCell<HashMap<String, String>>,
 
     #[config_path("flexbox_grid/gap.ron")]
-    gap_y: OnceCell<HashMap<&'static str, &'static str>>,
+    gap_y: OnceCell<HashMap<String, String>>,
+}
+
+#[derive(GetOnceCell, Default)]
+pub struct InteractivityConfig {
+    #[config_path("colors.ron")]
+    accent_color: OnceCell<HashMap<String, String>>,
+
+    #[config_path("colors.ron")]
+    caret_color: OnceCell<HashMap<String, String>>,
+
+    #[config_path("interactivity/cursor.ron")]
+    cursor: OnceCell<HashMap<String, String>>,
+
+    #[config_path("interactivity/scroll_margin.ron")]
+    scroll_margin: OnceCell<HashMap<String, String>>,
+
+    #[config_path("interactivity/scroll_padding.ron")]
+    scroll_padding: OnceCell<HashMap<String, String>>,
+}
+
+#[derive(GetOnceCell, Default)]
+pub struct LayoutConfig {
+    #[config_path("layout/aspect_ratio.ron")]
+    aspect_ratio: OnceCell<HashMap<String, String>>,
+
+    #[config_path("layout/columns.ron")]
+    columns: OnceCell<HashMap<String, String>>,
+
+    #[config_path("layout/object_position.ron")]
+    object_position: OnceCell<HashMap<String, String>>,
+
+    #[config_path("layout/inset.ron")]
+    inset: OnceCell<HashMap<String, String>>,
+
+    #[config_path("layout/z_index.ron")]
+    z_index: OnceCell<HashMap<String, String>>,
+}
+
+#[derive(GetOnceCell, Default)]
+pub struct SizingConfig {
+    #[config_path("sizing/width.ron")]
+    width: OnceCell<HashMap<String, String>>,
+
+    #[config_path("sizing/min_width.ron")]
+    min_width: OnceCell<HashMap<String, String>>,
+
+    #[config_path("sizing/max_width.ron")]
+    max_width: OnceCell<HashMap<String, String>>,
+
+    #[config_path("sizing/height.ron")]
+    height: OnceCell<HashMap<String, String>>,
+
+    #[config_path("sizing/min_height.ron")]
+    min_height: OnceCell<HashMap<String, String>>,
+
+    #[config_path("sizing/max_height.ron")]
+    max_height: OnceCell<HashMap<String, String>>,
+}
+
+#[derive(GetOnceCell, Default)]
+pub struct SvgConfig {
+    #[config_path("colors.ron")]
+    fill: OnceCell<HashMap<String, String>>,
+
+    #[config_path("colors.ron")]
+    stroke: OnceCell<HashMap<String, String>>,
+}
+
+#[derive(GetOnceCell, Default)]
+pub struct TablesConfig {
+    #[config_path("tables/border_spacing.ron")]
+    border_spacing: OnceCell<HashMap<String, String>>,
+}
+
+#[derive(GetOnceCell, Default)]
+pub struct TransformsConfig {
+    #[config_path("transforms/translate.ron")]
+    translate: OnceCell<HashMap<String, String>>,
+
+    #[config_path("transforms/rotate.ron")]
+    rotate: OnceCell<HashMap<String, String>>,
+
+    #[config_path("transforms/skew.ron")]
+    skew: OnceCell<HashMap<String, String>>,
+
+    #[config_path("transforms/scale.ron")]
+    scale: OnceCell<HashMap<String, String>>,
+
+    #[config_path("transforms/origin.ron")]
+    origin: OnceCell<HashMap<String, String>>,
+}
+
+#[derive(GetOnceCell, Default)]
+pub struct TransitionsAnimationConfig {
+    #[config_path("transitions_animation/duration.ron")]
+    duration: OnceCell<HashMap<String, String>>,
+
+    #[config_path("transitions_animation/timing_function.ron")]
+    timing_function: OnceCell<HashMap<String, String>>,
+
+    #[config_path("transitions_animation/delay.ron")]
+    delay: OnceCell<HashMap<String, String>>,
+
+    #[config_path("transitions_animation/animation.ron")]
+    animation: OnceCell<HashMap<String, String>>,
+
+    #[config_path("transitions_animation/keyframes.ron")]
+    keyframes: OnceCell<HashMap<String, Keyframes>>,
+}
+
+#[derive(GetOnceCell, Default)]
+pub struct TypographyConfig {
+    #[config_path("typography/font_family.ron")]
+    font_family: OnceCell<HashMap<String, String>>,
+
+    #[config_path("typography/font_size.ron")]
+    font_size: OnceCell<HashMap<String, (String, String)>>,
+
+    #[config_path("typography/font_weight.ron")]
+    font_weight: OnceCell<HashMap<String, String>>,
+
+    #[config_path("typography/letter_spacing.ron")]
+    letter_spacing: OnceCell<HashMap<String, String>>,
+
+    #[config_path("typography/line_height.ron")]
+    line_height: OnceCell<HashMap<String, String>>,
+
+    #[config_path("typography/list_style_type.ron")]
+    list_style_type: OnceCell<HashMap<String, String>>,
+
+    #[config_path("colors.ron")]
+    text_color: OnceCell<HashMap<String, String>>,
+
+    #[config_path("colors.ron")]
+    text_decoration_color: OnceCell<HashMap<String, String>>,
+
+    #[config_path("typography/text_decoration_thickness.ron")]
+    text_decoration_thickness: OnceCell<HashMap<String, String>>,
+
+    #[config_path("typography/text_underline_offset.ron")]
+    text_underline_offset: OnceCell<HashMap<String, String>>,
+
+    #[config_path("typography/text_indent.ron")]
+    text_indent: OnceCell<HashMap<String, String>>,
+
+    #[config_path("typography/content.ron")]
+    content: OnceCell<HashMap<String, String>>,
 }
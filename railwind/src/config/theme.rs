@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Config, Keyframes};
+use crate::modifiers::Screens;
+
+/// Theme tables of one category, like `{"color": {"brand": "#bada55"}}` for the backgrounds
+pub type Tables = HashMap<String, HashMap<String, String>>;
+
+/// Theme values that are only known at runtime, like the `theme` in `railwind.config.ron`.
+/// The tables of a category replace the built in ones, while the tables in `extend`
+/// are merged over them, so `extend` can add a colour without losing the others.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub backgrounds: Tables,
+    pub spacing: Tables,
+    pub borders: Tables,
+    pub effects: Tables,
+    pub filters: Tables,
+    pub flexbox_grid: Tables,
+    pub interactivity: Tables,
+    pub layout: Tables,
+    pub sizing: Tables,
+    pub svg: Tables,
+    pub tables: Tables,
+    pub transforms: Tables,
+    pub transitions_animation: Tables,
+    pub typography: Tables,
+    /// Replaces the built in screens, like `md`, if it's set
+    pub screens: Option<Screens>,
+    /// Replaces the built in sizes of the container queries, like `@md`, if it's set
+    pub containers: Option<HashMap<String, String>>,
+    /// Replaces the built in attributes of the `aria-*` variants, if it's set
+    pub aria: Option<HashMap<String, String>>,
+    /// The attributes of the `data-*` variants, like `"open": "state=open"`
+    pub data: Option<HashMap<String, String>>,
+    /// The conditions of the `supports-*` variants, like `"grid": "display: grid"`
+    pub supports: Option<HashMap<String, String>>,
+    /// Replaces the built in font sizes and their line heights, like `"huge": ("9rem", "1")`
+    pub font_size: Option<HashMap<String, (String, String)>>,
+    /// Replaces the built in `@keyframes` of the `animate-*` utilities
+    pub keyframes: Option<HashMap<String, Keyframes>>,
+    pub extend: ThemeExtension,
+}
+
+/// Values that are added to the built in theme tables, or change the ones that are already there
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeExtension {
+    pub backgrounds: Tables,
+    pub spacing: Tables,
+    pub borders: Tables,
+    pub effects: Tables,
+    pub filters: Tables,
+    pub flexbox_grid: Tables,
+    pub interactivity: Tables,
+    pub layout: Tables,
+    pub sizing: Tables,
+    pub svg: Tables,
+    pub tables: Tables,
+    pub transforms: Tables,
+    pub transitions_animation: Tables,
+    pub typography: Tables,
+    /// Added after the other screens, or changes the one with the same name where it is
+    pub screens: Screens,
+    pub containers: HashMap<String, String>,
+    pub aria: HashMap<String, String>,
+    pub data: HashMap<String, String>,
+    pub supports: HashMap<String, String>,
+    pub font_size: HashMap<String, (String, String)>,
+    pub keyframes: HashMap<String, Keyframes>,
+}
+
+/// A theme table that doesn't exist, like `backgrounds.colour`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTable {
+    pub category: &'static str,
+    pub table: String,
+    pub possible: &'static [&'static str],
+}
+
+impl fmt::Display for UnknownTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown theme table '{}.{}', possible tables: '{}'",
+            self.category,
+            self.table,
+            self.possible.join(", ")
+        )
+    }
+}
+
+/// A category of theme tables, like `BackgroundsConfig`, whose tables can be found by name
+pub trait ThemeCategory {
+    /// The table called `name`, like `color`, with the built in values loaded
+    fn table_mut(&mut self, name: &str) -> Option<&mut HashMap<String, String>>;
+
+    fn table_names(&self) -> &'static [&'static str];
+}
+
+impl Config {
+    /// Replaces and extends the built in theme tables. Every table that exists is applied,
+    /// even if some don't, and the ones that don't are returned.
+    pub fn apply_theme(&mut self, theme: &Theme) -> Result<(), Vec<UnknownTable>> {
+        let extend = &theme.extend;
+        let mut unknown = vec![];
+
+        let categories: [(&'static str, &mut dyn ThemeCategory, &Tables, &Tables); 14] = [
+            (
+                "backgrounds",
+                &mut self.backgrounds,
+                &theme.backgrounds,
+                &extend.backgrounds,
+            ),
+            (
+                "spacing",
+                &mut self.spacing,
+                &theme.spacing,
+                &extend.spacing,
+            ),
+            (
+                "borders",
+                &mut self.borders,
+                &theme.borders,
+                &extend.borders,
+            ),
+            (
+                "effects",
+                &mut self.effects,
+                &theme.effects,
+                &extend.effects,
+            ),
+            (
+                "filters",
+                &mut self.filters,
+                &theme.filters,
+                &extend.filters,
+            ),
+            (
+                "flexbox_grid",
+                &mut self.flexbox_grid,
+                &theme.flexbox_grid,
+                &extend.flexbox_grid,
+            ),
+            (
+                "interactivity",
+                &mut self.interactivity,
+                &theme.interactivity,
+                &extend.interactivity,
+            ),
+            ("layout", &mut self.layout, &theme.layout, &extend.layout),
+            ("sizing", &mut self.sizing, &theme.sizing, &extend.sizing),
+            ("svg", &mut self.svg, &theme.svg, &extend.svg),
+            ("tables", &mut self.tables, &theme.tables, &extend.tables),
+            (
+                "transforms",
+                &mut self.transforms,
+                &theme.transforms,
+                &extend.transforms,
+            ),
+            (
+                "transitions_animation",
+                &mut self.transitions_animation,
+                &theme.transitions_animation,
+                &extend.transitions_animation,
+            ),
+            (
+                "typography",
+                &mut self.typography,
+                &theme.typography,
+                &extend.typography,
+            ),
+        ];
+
+        for (category, config, replace, extend) in categories {
+            let tables = replace
+                .iter()
+                .map(|t| (t, false))
+                .chain(extend.iter().map(|t| (t, true)));
+
+            for ((table, values), extend) in tables {
+                match config.table_mut(table) {
+                    Some(t) if extend => t.extend(values.clone()),
+                    Some(t) => *t = values.clone(),
+                    None => unknown.push(UnknownTable {
+                        category,
+                        table: table.clone(),
+                        possible: config.table_names(),
+                    }),
+                }
+            }
+        }
+
+        if theme.screens.is_some() || !extend.screens.is_empty() {
+            let mut screens = theme
+                .screens
+                .clone()
+                .unwrap_or_else(|| self.variants.screens().clone());
+            screens.extend(extend.screens.clone());
+            self.variants.set_screens(screens);
+        }
+
+        let variants = &mut self.variants;
+        apply_table(
+            variants.containers_mut(),
+            &theme.containers,
+            &extend.containers,
+        );
+        apply_table(variants.aria_mut(), &theme.aria, &extend.aria);
+        apply_table(variants.data_mut(), &theme.data, &extend.data);
+        apply_table(variants.supports_mut(), &theme.supports, &extend.supports);
+
+        apply_table(
+            self.typography.get_mut_font_size(),
+            &theme.font_size,
+            &extend.font_size,
+        );
+        apply_table(
+            self.transitions_animation.get_mut_keyframes(),
+            &theme.keyframes,
+            &extend.keyframes,
+        );
+
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(unknown)
+        }
+    }
+}
+
+fn apply_table<T: Clone>(
+    table: &mut HashMap<String, T>,
+    replace: &Option<HashMap<String, T>>,
+    extend: &HashMap<String, T>,
+) {
+    if let Some(replace) = replace {
+        *table = replace.clone();
+    }
+    table.extend(extend.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modifiers::{Screen, Variant};
+
+    fn tables(table: &str, values: &[(&str, &str)]) -> Tables {
+        HashMap::from([(
+            table.to_string(),
+            values
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )])
+    }
+
+    #[test]
+    fn test_apply_theme() {
+        let mut config = Config::default();
+        let theme = Theme {
+            spacing: tables("padding", &[("gutter", "18px")]),
+            extend: ThemeExtension {
+                backgrounds: tables("color", &[("brand", "#bada55")]),
+                typography: tables("font_family", &[("display", "Oswald, sans-serif")]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(config.apply_theme(&theme), Ok(()));
+
+        let padding = config.spacing.get_padding();
+        assert_eq!(padding.get("gutter").map(String::as_str), Some("18px"));
+        assert_eq!(padding.get("4"), None);
+
+        let color = config.backgrounds.get_color();
+        assert_eq!(color.get("brand").map(String::as_str), Some("#bada55"));
+        assert_eq!(color.get("red-500").map(String::as_str), Some("#ef4444"));
+
+        let font_family = config.typography.get_font_family();
+        assert_eq!(
+            font_family.get("display").map(String::as_str),
+            Some("Oswald, sans-serif")
+        );
+        assert!(font_family.contains_key("sans"));
+    }
+
+    #[test]
+    fn test_apply_theme_screens() {
+        let mut config = Config::default();
+        let theme = Theme {
+            extend: ThemeExtension {
+                screens: Screens::from([
+                    ("md".to_string(), Screen::Min("800px".into())),
+                    ("3xl".to_string(), Screen::Min("1920px".into())),
+                ]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(config.apply_theme(&theme), Ok(()));
+
+        let variants = &config.variants;
+        assert_eq!(
+            variants.get("md"),
+            Some(Variant::at_rule("media", "(min-width: 800px)"))
+        );
+        assert!(variants.find("md").unwrap().1 < variants.find("lg").unwrap().1);
+        assert!(variants.find("2xl").unwrap().1 < variants.find("3xl").unwrap().1);
+        assert_eq!(variants.screens().len(), 6);
+    }
+
+    #[test]
+    fn test_apply_theme_containers() {
+        let mut config = Config::default();
+        let theme = Theme {
+            containers: Some(HashMap::from([("card".to_string(), "18rem".to_string())])),
+            ..Default::default()
+        };
+
+        assert_eq!(config.apply_theme(&theme), Ok(()));
+        assert_eq!(
+            config.variants.get("@card"),
+            Some(Variant::at_rule("container", "(min-width: 18rem)"))
+        );
+        assert_eq!(config.variants.get("@md"), None);
+    }
+
+    #[test]
+    fn test_apply_theme_attribute_variants() {
+        let mut config = Config::default();
+        let theme = Theme {
+            supports: Some(HashMap::from([(
+                "grid".to_string(),
+                "display: grid".to_string(),
+            )])),
+            extend: ThemeExtension {
+                aria: HashMap::from([("sort".to_string(), "sort=ascending".to_string())]),
+                data: HashMap::from([("open".to_string(), "state=open".to_string())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(config.apply_theme(&theme), Ok(()));
+
+        let variants = &config.variants;
+        assert_eq!(
+            variants.get("aria-sort"),
+            Some(Variant::selector("&[aria-sort=ascending]"))
+        );
+        assert_eq!(
+            variants.get("aria-checked"),
+            Some(Variant::selector("&[aria-checked=\"true\"]"))
+        );
+        assert_eq!(
+            variants.get("data-open"),
+            Some(Variant::selector("&[data-state=open]"))
+        );
+        assert_eq!(
+            variants.get("supports-grid"),
+            Some(Variant::at_rule("supports", "(display: grid)"))
+        );
+    }
+
+    #[test]
+    fn test_apply_theme_unknown_table() {
+        let mut config = Config::default();
+        let theme = Theme {
+            extend: ThemeExtension {
+                effects: tables("shadow", &[("huge", "0 0 9px black")]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let unknown = config.apply_theme(&theme).unwrap_err();
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(
+            unknown[0].to_string(),
+            "Unknown theme table 'effects.shadow', possible tables: 'box_shadow, box_shadow_color, opacity'"
+        );
+    }
+
+    #[test]
+    fn test_apply_theme_font_size_and_keyframes() {
+        let mut config = Config::default();
+        let wiggle: Keyframes = vec![(
+            "50%".into(),
+            vec![("transform".into(), "rotate(3deg)".into())],
+        )];
+        let theme = Theme {
+            extend: ThemeExtension {
+                font_size: HashMap::from([("huge".into(), ("9rem".into(), "1".into()))]),
+                keyframes: HashMap::from([("wiggle".into(), wiggle.clone())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(config.apply_theme(&theme), Ok(()));
+
+        let font_size = config.typography.get_font_size();
+        assert_eq!(
+            font_size.get("huge"),
+            Some(&("9rem".to_string(), "1".to_string()))
+        );
+        assert!(font_size.contains_key("base"));
+
+        let keyframes = config.transitions_animation.get_keyframes();
+        assert_eq!(keyframes.get("wiggle"), Some(&wiggle));
+        assert!(keyframes.contains_key("spin"));
+
+        let theme = Theme {
+            font_size: Some(HashMap::from([(
+                "huge".into(),
+                ("9rem".into(), "1".into()),
+            )])),
+            ..Default::default()
+        };
+
+        assert_eq!(config.apply_theme(&theme), Ok(()));
+        assert_eq!(config.typography.get_font_size().len(), 1);
+    }
+}
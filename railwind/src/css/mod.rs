@@ -0,0 +1,342 @@
+//! A small model of the stylesheets railwind generates.
+//!
+//! Every class is turned into a list of `Node`s, which can be inspected or changed
+//! before they are printed with `print`.
+
+mod parse;
+mod printer;
+mod source_map;
+
+pub(crate) use parse::parse_rules;
+pub use printer::{print, PrintMode, Printer};
+pub use source_map::{url_comment, Mapping, SourceMap};
+
+use std::fmt;
+
+use crate::utils::replace_invalid_chars;
+use crate::warning::Position;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Declaration {
+    pub property: String,
+    pub value: String,
+    pub important: bool,
+}
+
+impl Declaration {
+    pub fn new(property: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            property: property.into(),
+            value: value.into(),
+            important: false,
+        }
+    }
+
+    /// Marks the declaration with `!important`
+    pub fn important(mut self) -> Self {
+        self.important = true;
+        self
+    }
+}
+
+impl From<(&str, &str)> for Declaration {
+    fn from((property, value): (&str, &str)) -> Self {
+        Self::new(property, value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Selector(pub String);
+
+impl Selector {
+    pub fn new(selector: impl Into<String>) -> Self {
+        Self(selector.into())
+    }
+
+    /// The selector for a class as it was written in the source, like `.hover\:px-5`
+    pub fn class(raw_class_name: &str) -> Self {
+        Self(format!(".{}", replace_invalid_chars(raw_class_name)))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rule {
+    pub selector: Selector,
+    pub declarations: Vec<Declaration>,
+    /// Where the class this rule was generated from first appeared
+    pub source: Option<Position>,
+}
+
+impl Rule {
+    pub fn new(selector: Selector, declarations: Vec<Declaration>) -> Self {
+        Self {
+            selector,
+            declarations,
+            source: None,
+        }
+    }
+
+    pub fn source(mut self, position: Position) -> Self {
+        self.source = Some(position);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AtRuleKind {
+    Media,
+    Supports,
+    Keyframes,
+    Other(String),
+}
+
+impl AtRuleKind {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Media => "media",
+            Self::Supports => "supports",
+            Self::Keyframes => "keyframes",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AtRule {
+    pub kind: AtRuleKind,
+    pub params: String,
+    pub nodes: Vec<Node>,
+}
+
+impl AtRule {
+    pub fn new(kind: AtRuleKind, params: impl Into<String>, nodes: Vec<Node>) -> Self {
+        Self {
+            kind,
+            params: params.into(),
+            nodes,
+        }
+    }
+
+    pub fn media(params: impl Into<String>, nodes: Vec<Node>) -> Self {
+        Self::new(AtRuleKind::Media, params, nodes)
+    }
+
+    pub fn supports(params: impl Into<String>, nodes: Vec<Node>) -> Self {
+        Self::new(AtRuleKind::Supports, params, nodes)
+    }
+
+    pub fn keyframes(name: impl Into<String>, nodes: Vec<Node>) -> Self {
+        Self::new(AtRuleKind::Keyframes, name, nodes)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Node {
+    Rule(Rule),
+    AtRule(AtRule),
+}
+
+impl Node {
+    /// Points this rule, or every rule inside this at-rule, to `position`
+    pub fn set_source(&mut self, position: &Position) {
+        match self {
+            Node::Rule(rule) => rule.source = Some(position.clone()),
+            Node::AtRule(at_rule) => {
+                for node in at_rule.nodes.iter_mut() {
+                    node.set_source(position);
+                }
+            }
+        }
+    }
+}
+
+impl From<Rule> for Node {
+    fn from(rule: Rule) -> Self {
+        Self::Rule(rule)
+    }
+}
+
+impl From<AtRule> for Node {
+    fn from(at_rule: AtRule) -> Self {
+        Self::AtRule(at_rule)
+    }
+}
+
+/// Moves the children of every `@media` or `@supports` rule into the one right before it,
+/// if it has the same params, so a chain like `dark:md:` is only printed once. At-rules with
+/// a rule between them are kept apart, so the rule can't be moved past the other one.
+/// Keyframes are only printed once, like the ones of `animate-spin hover:animate-spin`.
+pub fn merge_at_rules(nodes: Vec<Node>) -> Vec<Node> {
+    merge(nodes, &[])
+}
+
+// `keyframes` are the names of the ones around the nodes, which apply to them already.
+// Keyframes outside of any at-rule apply everywhere, even before they are printed.
+fn merge(nodes: Vec<Node>, keyframes: &[String]) -> Vec<Node> {
+    let mut keyframes = keyframes.to_vec();
+    let mut out: Vec<Node> = Vec::with_capacity(nodes.len());
+
+    for node in nodes {
+        match node {
+            Node::AtRule(at_rule) if at_rule.kind == AtRuleKind::Keyframes => {
+                if !keyframes.contains(&at_rule.params) {
+                    keyframes.push(at_rule.params.clone());
+                    out.push(at_rule.into());
+                }
+            }
+            Node::AtRule(at_rule) => {
+                let previous = match out.last_mut() {
+                    Some(Node::AtRule(a))
+                        if a.kind == at_rule.kind && a.params == at_rule.params =>
+                    {
+                        Some(a)
+                    }
+                    _ => None,
+                };
+
+                match previous {
+                    Some(previous) => previous.nodes.extend(at_rule.nodes),
+                    None => out.push(at_rule.into()),
+                }
+            }
+            node => out.push(node),
+        }
+    }
+
+    for node in out.iter_mut() {
+        if let Node::AtRule(at_rule) = node {
+            if at_rule.kind != AtRuleKind::Keyframes {
+                at_rule.nodes = merge(std::mem::take(&mut at_rule.nodes), &keyframes);
+            }
+        }
+    }
+
+    // An at-rule that only had keyframes that are printed already isn't needed anymore
+    out.retain(|node| !matches!(node, Node::AtRule(a) if a.nodes.is_empty()));
+
+    out
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Stylesheet {
+    pub nodes: Vec<Node>,
+}
+
+impl Stylesheet {
+    pub fn new(nodes: Vec<Node>) -> Self {
+        Self { nodes }
+    }
+}
+
+impl fmt::Display for Stylesheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", print(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(class: &str) -> Node {
+        Rule::new(
+            Selector::class(class),
+            vec![Declaration::new("display", "flex")],
+        )
+        .into()
+    }
+
+    #[test]
+    fn test_merge_at_rules() {
+        let md = "(min-width: 768px)";
+        let dark = "(prefers-color-scheme: dark)";
+
+        let nodes = vec![
+            rule("flex"),
+            AtRule::media(md, vec![rule("md:flex")]).into(),
+            AtRule::media(
+                md,
+                vec![AtRule::media(dark, vec![rule("dark:md:flex")]).into()],
+            )
+            .into(),
+            AtRule::media(md, vec![rule("md:hover:flex")]).into(),
+            AtRule::media(
+                md,
+                vec![AtRule::media(dark, vec![rule("md:dark:flex")]).into()],
+            )
+            .into(),
+        ];
+
+        assert_eq!(
+            merge_at_rules(nodes),
+            vec![
+                rule("flex"),
+                AtRule::media(
+                    md,
+                    vec![
+                        rule("md:flex"),
+                        AtRule::media(dark, vec![rule("dark:md:flex")]).into(),
+                        rule("md:hover:flex"),
+                        AtRule::media(dark, vec![rule("md:dark:flex")]).into(),
+                    ]
+                )
+                .into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_at_rules_keeps_rules_in_between() {
+        let md = "(min-width: 768px)";
+        let nodes = vec![
+            AtRule::media(md, vec![rule("md:flex")]).into(),
+            rule("p-3"),
+            AtRule::media(md, vec![rule("md:p-1")]).into(),
+        ];
+
+        assert_eq!(merge_at_rules(nodes.clone()), nodes);
+    }
+
+    #[test]
+    fn test_merge_at_rules_dedups_keyframes() {
+        let md = "(min-width: 768px)";
+        let keyframes = |name: &str| -> Node { AtRule::keyframes(name, vec![rule("to")]).into() };
+
+        // The copies come from different classes, so they point to different sources
+        let mut copy = keyframes("spin");
+        copy.set_source(&Position::new("", 1, 14));
+
+        assert_eq!(
+            merge_at_rules(vec![
+                keyframes("spin"),
+                copy,
+                AtRule::media(md, vec![keyframes("spin"), keyframes("ping")]).into(),
+            ]),
+            vec![
+                keyframes("spin"),
+                AtRule::media(md, vec![keyframes("ping")]).into(),
+            ]
+        );
+
+        // The keyframes outside of any at-rule apply inside it too, but not the other way around
+        let in_md: Node = AtRule::media(md, vec![keyframes("spin"), rule("md:flex")]).into();
+        assert_eq!(
+            merge_at_rules(vec![in_md.clone(), keyframes("spin")]),
+            vec![
+                AtRule::media(md, vec![rule("md:flex")]).into(),
+                keyframes("spin")
+            ]
+        );
+        assert_eq!(merge_at_rules(vec![in_md.clone()]), vec![in_md]);
+    }
+}
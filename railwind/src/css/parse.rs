@@ -0,0 +1,80 @@
+use super::{Declaration, Node, Rule, Selector};
+
+/// Reads a stylesheet made only of plain rules, like the preflight. Comments are dropped
+/// and at-rules are not supported.
+pub(crate) fn parse_rules(css: &str) -> Vec<Node> {
+    let mut without_comments = String::with_capacity(css.len());
+    let mut rest = css;
+
+    while let Some(start) = rest.find("/*") {
+        without_comments.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find("*/")
+            .map_or("", |end| &rest[start + end + 2..]);
+    }
+    without_comments.push_str(rest);
+
+    without_comments
+        .split('}')
+        .filter_map(|block| {
+            let (selector, body) = block.split_once('{')?;
+
+            let declarations = body
+                .split(';')
+                .filter_map(|decl| {
+                    let (property, value) = decl.split_once(':')?;
+                    Some(Declaration::new(property.trim(), value.trim()))
+                })
+                .collect();
+
+            Some(
+                Rule::new(
+                    Selector::new(selector.split_whitespace().collect::<Vec<_>>().join(" ")),
+                    declarations,
+                )
+                .into(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rules() {
+        let css = r#"
+/* comment */
+*,
+::before {
+	box-sizing: border-box;
+	/* 1 */
+	border-width: 0;
+}
+
+abbr:where([title]) {
+	text-decoration: underline dotted;
+}
+"#;
+
+        assert_eq!(
+            parse_rules(css),
+            vec![
+                Rule::new(
+                    Selector::new("*, ::before"),
+                    vec![
+                        Declaration::new("box-sizing", "border-box"),
+                        Declaration::new("border-width", "0"),
+                    ]
+                )
+                .into(),
+                Rule::new(
+                    Selector::new("abbr:where([title])"),
+                    vec![Declaration::new("text-decoration", "underline dotted")]
+                )
+                .into(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,478 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AtRule, Mapping, Node, Rule, SourceMap, Stylesheet};
+use crate::warning::Position;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PrintMode {
+    /// Every declaration on its own line and an empty line between rules
+    #[default]
+    Pretty,
+    /// Every rule on a single line
+    Compact,
+    /// No whitespace, with the shortest number and colour forms
+    Minified,
+}
+
+/// Serialises a `Stylesheet` to CSS text
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Printer {
+    mode: PrintMode,
+    indent: usize,
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self::new(PrintMode::Pretty)
+    }
+}
+
+impl Printer {
+    pub fn new(mode: PrintMode) -> Self {
+        Self { mode, indent: 4 }
+    }
+
+    pub fn pretty() -> Self {
+        Self::new(PrintMode::Pretty)
+    }
+
+    pub fn compact() -> Self {
+        Self::new(PrintMode::Compact)
+    }
+
+    pub fn minified() -> Self {
+        Self::new(PrintMode::Minified)
+    }
+
+    /// Sets how many spaces nested rules are indented with, four by default.
+    /// Minified output is never indented.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    pub fn mode(&self) -> PrintMode {
+        self.mode
+    }
+
+    pub fn print(&self, stylesheet: &Stylesheet) -> String {
+        self.print_with_source_map(stylesheet).0
+    }
+
+    /// Prints a stylesheet and maps every rule that has a source back to it
+    pub fn print_with_source_map(&self, stylesheet: &Stylesheet) -> (String, SourceMap) {
+        let mut out = Output::default();
+        self.print_nodes(&stylesheet.nodes, 0, &mut out);
+
+        (out.css, SourceMap::new(out.mappings))
+    }
+
+    fn print_nodes(&self, nodes: &[Node], depth: usize, out: &mut Output) {
+        let separator = match self.mode {
+            PrintMode::Pretty => "\n\n",
+            PrintMode::Compact => "\n",
+            PrintMode::Minified => "",
+        };
+
+        for (i, node) in nodes.iter().enumerate() {
+            if i > 0 {
+                out.push_str(separator);
+            }
+
+            match node {
+                Node::Rule(rule) => self.print_rule(rule, depth, out),
+                Node::AtRule(at_rule) => self.print_at_rule(at_rule, depth, out),
+            }
+        }
+    }
+
+    fn print_rule(&self, rule: &Rule, depth: usize, out: &mut Output) {
+        let indent = self.indent_for(depth);
+
+        if self.mode != PrintMode::Minified {
+            out.push_str(&indent);
+        }
+
+        if let Some(source) = &rule.source {
+            out.add_mapping(source);
+        }
+
+        match self.mode {
+            PrintMode::Pretty => {
+                out.push_str(&format!("{} {{\n", rule.selector));
+
+                for decl in &rule.declarations {
+                    out.push_str(&format!(
+                        "{}{}{}: {}{};\n",
+                        indent,
+                        self.indent_for(1),
+                        decl.property,
+                        decl.value,
+                        if decl.important { " !important" } else { "" }
+                    ));
+                }
+
+                out.push_str(&indent);
+                out.push_str("}");
+            }
+            PrintMode::Compact => {
+                let declarations: String = rule
+                    .declarations
+                    .iter()
+                    .map(|decl| {
+                        format!(
+                            " {}: {}{};",
+                            decl.property,
+                            decl.value,
+                            if decl.important { " !important" } else { "" }
+                        )
+                    })
+                    .collect();
+
+                out.push_str(&format!("{} {{{} }}", rule.selector, declarations));
+            }
+            PrintMode::Minified => {
+                let declarations = rule
+                    .declarations
+                    .iter()
+                    .map(|decl| {
+                        let value = minify_value(&decl.value);
+
+                        format!(
+                            "{}:{}{}",
+                            decl.property,
+                            // Older browsers drop custom properties that are completely empty
+                            if value.is_empty() { " " } else { &value },
+                            if decl.important { "!important" } else { "" }
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(";");
+
+                out.push_str(&format!(
+                    "{}{{{}}}",
+                    minify_selector(rule.selector.as_str()),
+                    declarations
+                ));
+            }
+        }
+    }
+
+    fn print_at_rule(&self, at_rule: &AtRule, depth: usize, out: &mut Output) {
+        let indent = self.indent_for(depth);
+
+        match self.mode {
+            PrintMode::Pretty | PrintMode::Compact => {
+                out.push_str(&format!(
+                    "{}@{} {} {{\n",
+                    indent,
+                    at_rule.kind.name(),
+                    at_rule.params
+                ));
+                self.print_nodes(&at_rule.nodes, depth + 1, out);
+                out.push_str(&format!("\n{}}}", indent));
+            }
+            PrintMode::Minified => {
+                out.push_str(&format!(
+                    "@{} {}{{",
+                    at_rule.kind.name(),
+                    minify_selector(&at_rule.params)
+                ));
+                self.print_nodes(&at_rule.nodes, depth + 1, out);
+                out.push_str("}");
+            }
+        }
+    }
+
+    fn indent_for(&self, depth: usize) -> String {
+        " ".repeat(self.indent * depth)
+    }
+}
+
+/// The CSS printed so far and where every rule with a source was put
+#[derive(Default)]
+struct Output {
+    css: String,
+    line: usize,
+    column: usize,
+    mappings: Vec<Mapping>,
+}
+
+impl Output {
+    fn push_str(&mut self, str: &str) {
+        for c in str.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 0;
+            } else {
+                // Source maps count columns in UTF-16 code units
+                self.column += c.len_utf16();
+            }
+        }
+
+        self.css.push_str(str);
+    }
+
+    fn add_mapping(&mut self, source: &Position) {
+        self.mappings.push(Mapping {
+            generated_line: self.line,
+            generated_column: self.column,
+            source: source.clone(),
+        });
+    }
+}
+
+/// Prints a stylesheet with the default, pretty printer
+pub fn print(stylesheet: &Stylesheet) -> String {
+    Printer::default().print(stylesheet)
+}
+
+/// Removes the whitespace around combinators and commas, and after colons.
+/// Descendant combinators, like in `.group:hover .x`, are kept.
+fn minify_selector(selector: &str) -> String {
+    let mut out = String::with_capacity(selector.len());
+    let mut pending_space = false;
+
+    for c in selector.chars() {
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+
+        if pending_space
+            && !matches!(c, '>' | '~' | '+' | ',' | ')')
+            && !out.ends_with(['>', '~', '+', ',', ':', '('])
+            && !out.is_empty()
+        {
+            out.push(' ');
+        }
+
+        pending_space = false;
+        out.push(c);
+    }
+
+    out
+}
+
+/// Collapses whitespace, removes the spaces after commas
+/// and uses the shortest form of every number and hex colour outside of strings
+pub(crate) fn minify_value(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut out = String::with_capacity(value.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        match c {
+            '"' | '\'' => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && chars[i] != c {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                i = (i + 1).min(chars.len());
+                out.extend(&chars[start..i]);
+            }
+            c if c.is_whitespace() => {
+                while i < chars.len() && chars[i].is_whitespace() {
+                    i += 1;
+                }
+                if !out.is_empty()
+                    && !out.ends_with([',', '('])
+                    && i < chars.len()
+                    && chars[i] != ','
+                    && chars[i] != ')'
+                {
+                    out.push(' ');
+                }
+            }
+            '#' if is_boundary(&out) => {
+                let start = i + 1;
+                i = start;
+                while i < chars.len() && chars[i].is_ascii_hexdigit() {
+                    i += 1;
+                }
+                let hex: String = chars[start..i].iter().collect();
+                out.push_str(&shortest_color(&hex));
+            }
+            c if (c.is_ascii_digit() || (c == '.' && next_is_digit(&chars, i)))
+                && is_boundary(&out) =>
+            {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let number: String = chars[start..i].iter().collect();
+                out.push_str(&shortest_number(&number));
+            }
+            _ => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+fn next_is_digit(chars: &[char], i: usize) -> bool {
+    chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())
+}
+
+// Numbers and colours only start after a separator, so `--tw-ring-offset-width`
+// or `translate3d` are left alone
+fn is_boundary(out: &str) -> bool {
+    let trimmed = out.strip_suffix('-').unwrap_or(out);
+
+    match trimmed.chars().last() {
+        None => true,
+        Some(c) => matches!(c, ' ' | '(' | ',' | '/' | '*' | '+'),
+    }
+}
+
+fn shortest_number(number: &str) -> String {
+    let Some((integer, fraction)) = number.split_once('.') else {
+        return number.to_string();
+    };
+
+    let fraction = fraction.trim_end_matches('0');
+    let integer = integer.trim_start_matches('0');
+
+    match (integer.is_empty(), fraction.is_empty()) {
+        (true, true) => "0".into(),
+        (false, true) => integer.into(),
+        (_, false) => format!("{}.{}", integer, fraction),
+    }
+}
+
+fn shortest_color(hex: &str) -> String {
+    let hex = hex.to_ascii_lowercase();
+    let bytes = hex.as_bytes();
+
+    let short =
+        if (hex.len() == 6 || hex.len() == 8) && bytes.chunks(2).all(|pair| pair[0] == pair[1]) {
+            bytes.chunks(2).map(|pair| pair[0] as char).collect()
+        } else {
+            hex
+        };
+
+    match short.as_str() {
+        "f00" => "red".into(),
+        "d2b48c" => "tan".into(),
+        _ => format!("#{}", short),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::{Declaration, Selector};
+
+    fn stylesheet() -> Stylesheet {
+        Stylesheet::new(vec![
+            Rule::new(
+                Selector::class("px-5"),
+                vec![
+                    Declaration::new("padding-left", "1.25rem"),
+                    Declaration::new("padding-right", "1.25rem").important(),
+                ],
+            )
+            .into(),
+            AtRule::media(
+                "(min-width: 640px)",
+                vec![Rule::new(
+                    Selector::class("sm:flex"),
+                    vec![Declaration::new("display", "flex")],
+                )
+                .into()],
+            )
+            .into(),
+        ])
+    }
+
+    #[test]
+    fn test_print() {
+        assert_eq!(
+            print(&stylesheet()),
+            r#".px-5 {
+    padding-left: 1.25rem;
+    padding-right: 1.25rem !important;
+}
+
+@media (min-width: 640px) {
+    .sm\:flex {
+        display: flex;
+    }
+}"#
+        );
+
+        assert_eq!(
+            Printer::pretty().indent(2).print(&stylesheet()),
+            r#".px-5 {
+  padding-left: 1.25rem;
+  padding-right: 1.25rem !important;
+}
+
+@media (min-width: 640px) {
+  .sm\:flex {
+    display: flex;
+  }
+}"#
+        );
+    }
+
+    #[test]
+    fn test_print_compact() {
+        assert_eq!(
+            Printer::compact().print(&stylesheet()),
+            r#".px-5 { padding-left: 1.25rem; padding-right: 1.25rem !important; }
+@media (min-width: 640px) {
+    .sm\:flex { display: flex; }
+}"#
+        );
+    }
+
+    #[test]
+    fn test_print_minified() {
+        assert_eq!(
+            Printer::minified().print(&stylesheet()),
+            r#".px-5{padding-left:1.25rem;padding-right:1.25rem!important}@media (min-width:640px){.sm\:flex{display:flex}}"#
+        );
+    }
+
+    #[test]
+    fn test_minify_selector() {
+        assert_eq!(
+            minify_selector(".space-x-4 > :not([hidden]) ~ :not([hidden])"),
+            ".space-x-4>:not([hidden])~:not([hidden])"
+        );
+        assert_eq!(
+            minify_selector(".group:hover .group-hover\\:flex"),
+            ".group:hover .group-hover\\:flex"
+        );
+        assert_eq!(minify_selector("0%, 100%"), "0%,100%");
+    }
+
+    #[test]
+    fn test_minify_value() {
+        assert_eq!(
+            minify_value("cubic-bezier(0.4, 0, 0.20, 1)"),
+            "cubic-bezier(.4,0,.2,1)"
+        );
+        assert_eq!(minify_value("-0.5rem"), "-.5rem");
+        assert_eq!(minify_value("1.0"), "1");
+        assert_eq!(minify_value("#FFFFFF"), "#fff");
+        assert_eq!(minify_value("#ff0000"), "red");
+        assert_eq!(minify_value("#1e293b"), "#1e293b");
+        assert_eq!(
+            minify_value("var(--tw-ring-offset-width) solid #ffffff88"),
+            "var(--tw-ring-offset-width) solid #fff8"
+        );
+        assert_eq!(minify_value("\"0.50  a\""), "\"0.50  a\"");
+        assert_eq!(
+            minify_value("var(--tw-ordinal)\n        var(--tw-slashed-zero)"),
+            "var(--tw-ordinal) var(--tw-slashed-zero)"
+        );
+    }
+}
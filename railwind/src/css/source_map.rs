@@ -0,0 +1,324 @@
+use std::path::{self, Component, Path};
+
+use serde::Serialize;
+
+use crate::warning::Position;
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Where a rule was printed and the class it was generated from.
+/// Generated lines and columns start at 0.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mapping {
+    pub generated_line: usize,
+    pub generated_column: usize,
+    pub source: Position,
+}
+
+/// A Source Map v3 that points every printed rule back to the template
+/// position where its class first appeared
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SourceMap {
+    file: Option<String>,
+    mappings: Vec<Mapping>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Json<'a> {
+    version: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<&'a str>,
+    sources: Vec<&'a str>,
+    names: Vec<&'a str>,
+    mappings: String,
+}
+
+impl SourceMap {
+    /// Classes that weren't read from a file have nothing to point to, so they are left out
+    pub fn new(mappings: Vec<Mapping>) -> Self {
+        Self {
+            file: None,
+            mappings: mappings
+                .into_iter()
+                .filter(|m| !m.source.file().is_empty())
+                .collect(),
+        }
+    }
+
+    /// Sets the name of the CSS file the map belongs to
+    pub fn file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Makes the paths of the sources relative to `dir`, the directory the map is written to,
+    /// so devtools can find them no matter where the compiler was run from
+    pub fn sources_relative_to(mut self, dir: impl AsRef<Path>) -> Self {
+        for mapping in self.mappings.iter_mut() {
+            let source = &mapping.source;
+            mapping.source = Position::new(
+                relative_path(Path::new(source.file()), dir.as_ref()),
+                source.line(),
+                source.column(),
+            );
+        }
+        self
+    }
+
+    pub fn mappings(&self) -> &[Mapping] {
+        &self.mappings
+    }
+
+    /// Moves every mapping down, for when something is printed in front of the stylesheet
+    pub fn offset_lines(&mut self, lines: usize) {
+        for mapping in self.mappings.iter_mut() {
+            mapping.generated_line += lines;
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut sources: Vec<&str> = vec![];
+        for mapping in &self.mappings {
+            if !sources.contains(&mapping.source.file()) {
+                sources.push(mapping.source.file());
+            }
+        }
+
+        let json = Json {
+            version: 3,
+            file: self.file.as_deref(),
+            mappings: self.encode_mappings(&sources),
+            sources,
+            names: vec![],
+        };
+
+        serde_json::to_string(&json).unwrap()
+    }
+
+    /// A comment that embeds the whole map, to be put at the end of the CSS
+    pub fn to_inline_comment(&self) -> String {
+        url_comment(&format!(
+            "data:application/json;base64,{}",
+            base64(self.to_json().as_bytes())
+        ))
+    }
+
+    fn encode_mappings(&self, sources: &[&str]) -> String {
+        let mut mappings = self.mappings.clone();
+        mappings.sort_by_key(|m| (m.generated_line, m.generated_column));
+
+        let mut out = String::new();
+        let mut line = 0;
+        let mut line_has_segment = false;
+        let mut previous_column = 0;
+        let mut previous_source = 0;
+        let mut previous_source_line = 0;
+        let mut previous_source_column = 0;
+
+        for mapping in mappings {
+            // Generated columns are relative to the start of their line
+            while line < mapping.generated_line {
+                out.push(';');
+                line += 1;
+                line_has_segment = false;
+                previous_column = 0;
+            }
+
+            if line_has_segment {
+                out.push(',');
+            }
+
+            let source = sources
+                .iter()
+                .position(|s| *s == mapping.source.file())
+                .unwrap_or(0);
+            // Positions start at 1, while source maps count from 0
+            let source_line = mapping.source.line().saturating_sub(1);
+            let source_column = mapping.source.column().saturating_sub(1);
+
+            encode_vlq(&mut out, mapping.generated_column, previous_column);
+            encode_vlq(&mut out, source, previous_source);
+            encode_vlq(&mut out, source_line, previous_source_line);
+            encode_vlq(&mut out, source_column, previous_source_column);
+
+            previous_column = mapping.generated_column;
+            previous_source = source;
+            previous_source_line = source_line;
+            previous_source_column = source_column;
+            line_has_segment = true;
+        }
+
+        out
+    }
+}
+
+/// The comment that tells browsers where to find the source map of a stylesheet
+pub fn url_comment(url: &str) -> String {
+    format!("/*# sourceMappingURL={} */", url)
+}
+
+// The path from `dir` to `path`, with forward slashes like the URLs of a source map.
+// A path that can't be made relative, like one on another drive, is kept the way it is.
+fn relative_path(path: &Path, dir: &Path) -> String {
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    let (Ok(absolute_path), Ok(absolute_dir)) = (path::absolute(path), path::absolute(dir)) else {
+        return path.display().to_string();
+    };
+    let (path, dir) = (normalize(&absolute_path), normalize(&absolute_dir));
+
+    let common = path.iter().zip(&dir).take_while(|(p, d)| p == d).count();
+    if common == 0 {
+        return absolute_path.display().to_string();
+    }
+
+    let parents = dir[common..].iter().map(|_| "..".to_string());
+    let rest = path[common..]
+        .iter()
+        .map(|c| c.as_os_str().to_string_lossy().to_string());
+
+    parents.chain(rest).collect::<Vec<_>>().join("/")
+}
+
+fn normalize(path: &Path) -> Vec<Component<'_>> {
+    let mut components = vec![];
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => {
+                components.pop();
+            }
+            c => components.push(c),
+        }
+    }
+
+    components
+}
+
+fn encode_vlq(out: &mut String, value: usize, previous: usize) {
+    let delta = value as i64 - previous as i64;
+    let mut vlq = if delta < 0 {
+        ((-delta) << 1) | 1
+    } else {
+        delta << 1
+    };
+
+    loop {
+        let mut digit = vlq & 0b11111;
+        vlq >>= 5;
+
+        if vlq > 0 {
+            digit |= 0b100000;
+        }
+
+        out.push(BASE64_CHARS[digit as usize] as char);
+
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as usize) << 16 | (b[1] as usize) << 8 | b[2] as usize;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_CHARS[(n >> (18 - i * 6)) & 0b111111] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vlq(value: usize, previous: usize) -> String {
+        let mut out = String::new();
+        encode_vlq(&mut out, value, previous);
+        out
+    }
+
+    #[test]
+    fn test_encode_vlq() {
+        assert_eq!(vlq(0, 0), "A");
+        assert_eq!(vlq(1, 0), "C");
+        assert_eq!(vlq(0, 1), "D");
+        assert_eq!(vlq(16, 0), "gB");
+        assert_eq!(vlq(1000, 0), "w+B");
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let path = |path, dir| relative_path(Path::new(path), Path::new(dir));
+
+        assert_eq!(
+            path("/site/src/index.html", "/site/dist"),
+            "../src/index.html"
+        );
+        assert_eq!(path("/site/index.html", "/site"), "index.html");
+        assert_eq!(
+            path("/site/./src/../index.html", "/site/dist/"),
+            "../index.html"
+        );
+        assert_eq!(path("src/index.html", ""), "src/index.html");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let map = SourceMap::new(vec![
+            Mapping {
+                generated_line: 0,
+                generated_column: 0,
+                source: Position::new("index.html", 3, 13),
+            },
+            Mapping {
+                generated_line: 5,
+                generated_column: 4,
+                source: Position::new("src/main.rs", 1, 1),
+            },
+            Mapping {
+                generated_line: 5,
+                generated_column: 20,
+                source: Position::new("index.html", 2, 1),
+            },
+            Mapping {
+                generated_line: 6,
+                generated_column: 0,
+                source: Position::new("", 1, 1),
+            },
+        ])
+        .file("railwind.css");
+
+        assert_eq!(
+            map.to_json(),
+            r#"{"version":3,"file":"railwind.css","sources":["index.html","src/main.rs"],"names":[],"mappings":"AAEY;;;;;ICFZ,gBDCA"}"#
+        );
+    }
+}
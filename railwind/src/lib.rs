@@ -1,420 +1,442 @@
-// use indexmap::IndexMap;
-// use modifiers::{generate_state_selector, MediaQuery, State};
-// use serde::{Deserialize, Serialize};
-// use utils::{indent_string, replace_invalid_chars};
-// use warning::{Position, Warning, WarningType};
-
-// use lazy_static::lazy_static;
-// use line_col::LineColLookup;
-// use regex::Regex;
-// use std::collections::HashMap;
-// use std::fs::{read_to_string, File};
-// use std::io::Write;
-// use std::path::PathBuf;
+pub use class::UtilityPlugin;
+use class::{Borders, Class, Spacing};
+pub use compiler::Compiler;
+use config::Config;
+use css::{merge_at_rules, Node, Selector};
+use indexmap::IndexMap;
+use modifiers::State;
+pub use modifiers::{DarkMode, Screen, Screens, Variant, VariantPlugin, Variants};
+use order::SortKey;
+use serde::{Deserialize, Serialize};
+use utils::{normalize_arbitrary_values, replace_invalid_chars};
+use warning::{Position, Warning, WarningType};
+
+use lazy_static::lazy_static;
+use line_col::LineColLookup;
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
 
 mod class;
-mod config;
+mod compiler;
+pub mod config;
+pub mod css;
 mod modifiers;
+mod order;
 mod parser;
 mod utils;
 pub mod warning;
 
-// lazy_static! {
-//     static ref HTML_CLASS_REGEX: Regex =
-//         Regex::new(r#"(?:class|className)=(?:["]\W+\s*(?:\w+)\()?["]([^"]+)["]"#).unwrap();
-//     static ref PREFLIGHT: &'static str = include_str!("../preflight.css");
-// }
-
-// #[derive(Debug, PartialEq)]
-// pub struct ParsedClass<'a> {
-//     pub raw_class_name: &'a str,
-//     //pub class: Class<'a>,
-//     pub states: Vec<State>,
-//     pub position: Position,
-// }
-
-// impl<'a> ParsedClass<'a> {
-//     pub fn new(raw_class_name: &'a str, states: Vec<State>, position: Position) -> Self {
-//         Self {
-//             raw_class_name,
-
-//             states,
-//             position,
-//         }
-//     }
-
-//     pub fn new_from_raw_class(raw_class: &'a str, position: Position) -> Result<Self, Warning> {
-//         if let Some(colon_index) = raw_class.rfind(':') {
-//             // if an arbitrary value inside [...] contains a colon
-//             if let Some(left_bracket_index) = raw_class.rfind('[') {
-//                 if left_bracket_index < colon_index {
-//                     return Ok(Self::new(raw_class, vec![], position.clone()));
-//                 }
-//             }
-
-//             let states = &raw_class[..colon_index];
-//             let entire_class = &raw_class[colon_index + 1..];
-
-//             let mut parsed_states = vec![];
-//             for state in states.split(':') {
-//                 parsed_states.push(State::new(raw_class, state, &position)?)
-//             }
-
-//             Ok(Self::new(
-//                 raw_class,
-//                 Class::new(raw_class).unwrap(),
-//                 parsed_states,
-//                 position.clone(),
-//             ))
-//         } else {
-//             Ok(Self::new(
-//                 raw_class,
-//                 Class::new(raw_class).unwrap(),
-//                 vec![],
-//                 position.clone(),
-//             ))
-//         }
-//     }
-
-//     pub fn try_to_string(self) -> Result<String, WarningType> {
-//         let selector_to_append = match &self.class {
-//             Class::Spacing(spacing) => match spacing {
-//                 Spacing::SpaceBetween(_) => Some("> :not([hidden]) ~ :not([hidden])"),
-//                 _ => None,
-//             },
-//             Class::Borders(borders) => match borders {
-//                 Borders::DivideWidth(_) => Some("> :not([hidden]) ~ :not([hidden])"),
-//                 _ => None,
-//             },
-//             _ => None,
-//         };
-
-//         let decl = self.class.to_decl()?;
-
-//         let mut class_selector = if self.states.iter().any(|s| match s {
-//             State::PseudoClass(_) | State::PseudoElement(_) => true,
-//             _ => false,
-//         }) {
-//             format!(
-//                 "{}:{}",
-//                 replace_invalid_chars(self.raw_class_name),
-//                 generate_state_selector(self.states.clone())
-//             )
-//         } else {
-//             replace_invalid_chars(self.raw_class_name)
-//         };
-
-//         for state in self.states.iter() {
-//             match state {
-//                 State::Group(g) => {
-//                     class_selector = format!("{}{}", g.to_static_str(), class_selector)
-//                 }
-//                 State::Peer(p) => {
-//                     class_selector = format!("{}{}", p.to_static_str(), class_selector)
-//                 }
-//                 _ => (),
-//             }
-//         }
-
-//         if let Some(to_append) = selector_to_append {
-//             class_selector = format!("{} {}", class_selector, to_append);
-//         }
-
-//         let mut generated_class = match decl {
-//             Decl::FullClass(_) => decl.to_string().replace("container", &class_selector),
-//             _ => format!(".{} {{\n    {};\n}}", class_selector, decl.to_string()),
-//         };
-
-//         for state in self.states {
-//             match state {
-//                 State::MediaQuery(mq) => match mq {
-//                     MediaQuery::Sm
-//                     | MediaQuery::Md
-//                     | MediaQuery::Lg
-//                     | MediaQuery::Xl
-//                     | MediaQuery::Xxl
-//                     | MediaQuery::Dark
-//                     | MediaQuery::MotionReduce
-//                     | MediaQuery::MotionSafe
-//                     | MediaQuery::ContrastMore
-//                     | MediaQuery::ContrastLess
-//                     | MediaQuery::Portrait
-//                     | MediaQuery::Landscape => {
-//                         generated_class = format!(
-//                             "@media ({}) {{\n{}}}",
-//                             mq.to_static_str(),
-//                             indent_string(&generated_class)
-//                         );
-//                     }
-//                     _ => (),
-//                 },
-//                 _ => (),
-//             }
-//         }
-
-//         Ok(generated_class)
-//     }
-// }
-
-// pub struct SourceOptions<'a> {
-//     pub input: &'a PathBuf,
-//     pub option: CollectionOptions,
-// }
-
-// pub enum Source<'a> {
-//     File(SourceOptions<'a>),
-//     Files(Vec<SourceOptions<'a>>),
-//     String(String, CollectionOptions),
-// }
-
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// pub enum CollectionOptions {
-//     Html,
-//     String,
-//     #[serde(with = "serde_regex")]
-//     Regex(Regex),
-// }
-
-// impl CollectionOptions {
-//     pub fn new(value: &str, expand: Option<HashMap<String, CollectionOptions>>) -> Self {
-//         if let Some(exp) = expand {
-//             if let Some(opt) = exp.get(value) {
-//                 return opt.clone();
-//             }
-//         }
-
-//         match value {
-//             "html" => CollectionOptions::Html,
-//             _ => CollectionOptions::String,
-//         }
-//     }
-// }
-
-// /// A convenience function to write a `Source` to a `railwind` CSS file
-// pub fn parse_to_file(
-//     source: Source,
-//     output: &str,
-//     include_preflight: bool,
-//     warnings: &mut Vec<Warning>,
-// ) {
-//     let css = parse_to_string(source, include_preflight, warnings);
-
-//     let mut file = File::create(output).unwrap();
-//     file.write_all(css.as_bytes()).unwrap();
-// }
-
-// /// Parses a source to a `railwind` CSS string
-// pub fn parse_to_string(
-//     source: Source,
-//     include_preflight: bool,
-//     warnings: &mut Vec<Warning>,
-// ) -> String {
-//     let mut css = if include_preflight {
-//         PREFLIGHT.to_string()
-//     } else {
-//         String::new()
-//     };
-
-//     match source {
-//         Source::File(opt) => {
-//             let file_string = read_to_string(opt.input).unwrap();
-//             let raw_classes: IndexMap<&str, Position> = match opt.option {
-//                 CollectionOptions::Html => collect_with_regex(&file_string, &HTML_CLASS_REGEX),
-//                 CollectionOptions::String => collect(&file_string),
-//                 CollectionOptions::Regex(r) => collect_with_regex(&file_string, &r),
-//             };
-//             let parsed_classes = parse_classes(raw_classes, warnings);
-//             let generated_classes = generate_strings(parsed_classes, warnings);
-
-//             css.push_str(&generated_classes.join("\n\n"));
-//         }
-//         Source::Files(opts) => {
-//             let mut raw_string_classes: IndexMap<String, Position> = IndexMap::new();
-
-//             for opt in opts {
-//                 let file_string = read_to_string(opt.input).unwrap();
-
-//                 for (raw_str, position) in match opt.option {
-//                     CollectionOptions::Html => collect_with_regex(&file_string, &HTML_CLASS_REGEX),
-//                     CollectionOptions::String => collect(&file_string),
-//                     CollectionOptions::Regex(r) => collect_with_regex(&file_string, &r),
-//                 } {
-//                     raw_string_classes.insert(raw_str.to_string(), position);
-//                 }
-//             }
-
-//             let mut raw_classes: IndexMap<&str, Position> = IndexMap::new();
-//             for (c, p) in &raw_string_classes {
-//                 raw_classes.insert(&c, p.clone());
-//             }
-
-//             let parsed_classes = parse_classes(raw_classes, warnings);
-//             let generated_classes = generate_strings(parsed_classes, warnings);
-
-//             css.push_str(&generated_classes.join("\n\n"));
-//         }
-//         Source::String(str, opt) => {
-//             let raw_classes: IndexMap<&str, Position> = match opt {
-//                 CollectionOptions::Html => collect_with_regex(&str, &HTML_CLASS_REGEX),
-//                 CollectionOptions::String => collect(&str),
-//                 CollectionOptions::Regex(r) => collect_with_regex(&str, &r),
-//             };
-
-//             let parsed_classes = parse_classes(raw_classes, warnings);
-
-//             let generated_classes = generate_strings(parsed_classes, warnings);
-
-//             css.push_str(&generated_classes.join("\n\n"));
-//         }
-//     }
-
-//     css.push_str("\n");
-//     css
-// }
-
-// fn collect_with_regex<'a>(str: &'a str, regex: &Regex) -> IndexMap<&'a str, Position> {
-//     let lookup = LineColLookup::new(str);
-
-//     let mut raw_classes = IndexMap::new();
-
-//     for captures in regex.captures_iter(str) {
-//         if let Some(group) = captures.get(1) {
-//             let mut index = group.start();
-
-//             for cap in group.as_str().split([' ', '\n']) {
-//                 if cap.is_empty() || (cap == "group") || (cap == "peer") {
-//                     index += cap.len() + 1;
-//                     continue;
-//                 }
-
-//                 let position: Position = lookup.get(index).into();
-//                 raw_classes.insert(cap, position);
-
-//                 index += cap.len() + 1;
-//             }
-//         }
-//     }
-
-//     raw_classes
-// }
-
-// fn collect(str: &str) -> IndexMap<&str, Position> {
-//     let lookup = LineColLookup::new(str);
-//     let mut classes = IndexMap::new();
-//     let mut index = 0;
-
-//     for cap in str.split([' ', '\n']) {
-//         if cap.is_empty() {
-//             index += cap.len() + 1;
-//             continue;
-//         }
-
-//         let position = lookup.get(index).into();
-//         classes.insert(cap, position);
-
-//         index += cap.len() + 1;
-//     }
-
-//     classes
-// }
-
-// fn parse_classes<'a>(
-//     raw_classes: IndexMap<&'a str, Position>,
-//     warnings: &mut Vec<Warning>,
-// ) -> Vec<ParsedClass<'a>> {
-//     raw_classes
-//         .iter()
-//         .filter_map(|(raw_str, position)| {
-//             match ParsedClass::new_from_raw_class(raw_str, position.clone()) {
-//                 Ok(c) => Some(c),
-//                 Err(w) => {
-//                     warnings.push(w);
-//                     None
-//                 }
-//             }
-//         })
-//         .collect()
-// }
-
-// fn generate_strings<'a>(
-//     parsed_classes: Vec<ParsedClass<'a>>,
-//     warnings: &mut Vec<Warning>,
-// ) -> Vec<String> {
-//     let mut out = vec![];
-
-//     for class in parsed_classes {
-//         let pos = class.position.clone();
-//         let raw = class.raw_class_name;
-
-//         match class.try_to_string() {
-//             Ok(c) => out.push(c),
-//             Err(w) => warnings.push(Warning::new(raw, &pos, w)),
-//         }
-//     }
-
-//     out
-// }
-
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-
-//     #[test]
-//     fn test_collect_classes_from_str() {
-//         let text = "px-5 justify-start container";
-//         let classes = collect(text);
-
-//         assert!(!classes.is_empty());
-//         assert_eq!(
-//             classes,
-//             IndexMap::from([
-//                 ("px-5", Position::new("", 1, 1)),
-//                 ("justify-start", Position::new("", 1, 6)),
-//                 ("container", Position::new("", 1, 20))
-//             ])
-//         );
-//     }
-
-//     #[test]
-//     fn test_parse_classes_fail() {
-//         let text = "space-c-4";
-//         let mut warnings = vec![];
-//         let _ = parse_to_string(
-//             Source::String(text.into(), CollectionOptions::String),
-//             false,
-//             &mut warnings,
-//         );
-
-//         assert!(!warnings.is_empty())
-//     }
-
-//     #[test]
-//     fn test_collect_classes_from_html() {
-//         let text = r#"class="px-5 justify-start container""#;
-//         let classes = collect_with_regex(text, &HTML_CLASS_REGEX);
-
-//         assert!(!classes.is_empty());
-//         assert_eq!(
-//             classes,
-//             IndexMap::from([
-//                 ("px-5", Position::new("", 1, 8)),
-//                 ("justify-start", Position::new("", 1, 13)),
-//                 ("container", Position::new("", 1, 27))
-//             ])
-//         );
-//     }
-
-//     #[test]
-//     fn test_collection_options() {
-//         let opts = CollectionOptions::new("html", None);
-
-//         assert!(matches!(opts, CollectionOptions::Html));
-
-//         let opts = CollectionOptions::new(
-//             "rs",
-//             Some(HashMap::from([("rs".to_string(), CollectionOptions::Html)])),
-//         );
-
-//         assert!(matches!(opts, CollectionOptions::Html));
-//     }
-// }
+lazy_static! {
+    static ref PREFLIGHT: &'static str = include_str!("../preflight.css");
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParsedClass<'a> {
+    pub raw_class_name: &'a str,
+    pub class: Class<'a>,
+    pub states: Vec<State>,
+    pub position: Position,
+}
+
+impl<'a> ParsedClass<'a> {
+    pub fn new(
+        raw_class_name: &'a str,
+        class: Class<'a>,
+        states: Vec<State>,
+        position: Position,
+    ) -> Self {
+        Self {
+            raw_class_name,
+            class,
+            states,
+            position,
+        }
+    }
+
+    /// Parses a class as it was written in the source. `normalized` is the same class
+    /// after `normalize_arbitrary_values`, which is what the states and the value are read from.
+    pub fn new_from_raw_class(
+        raw_class: &'a str,
+        normalized: &'a str,
+        position: Position,
+        config: &'a Config,
+    ) -> Result<Self, Warning> {
+        let (value, raw_states) = parser::states(normalized)
+            .map_err(|_| Warning::new(raw_class, &position, WarningType::ClassNotFound))?;
+
+        let mut states = vec![];
+        for state in raw_states {
+            states.push(State::new(raw_class, state, &position, &config.variants)?);
+        }
+
+        let class = Class::new(value, config).map_err(|w| Warning::new(raw_class, &position, w))?;
+
+        Ok(Self::new(raw_class, class, states, position))
+    }
+
+    pub fn try_into_nodes(self, config: &Config) -> Result<Vec<Node>, WarningType> {
+        let selector_to_append = match &self.class {
+            Class::Spacing(Spacing::SpaceBetween(_)) => Some("> :not([hidden]) ~ :not([hidden])"),
+            Class::Borders(Borders::DivideWidth(_))
+            | Class::Borders(Borders::DivideColor(_))
+            | Class::Borders(Borders::DivideStyle(_)) => Some("> :not([hidden]) ~ :not([hidden])"),
+            _ => None,
+        };
+
+        // The variant closest to the class is applied first, so `group-hover:peer-focus:p-2`
+        // becomes `.group:hover .peer:focus ~ .x` and `[&_p]:hover:underline` becomes
+        // `.x:hover p`. Pseudo elements always stay at the end of the selector.
+        let mut selector = format!(".{}", replace_invalid_chars(self.raw_class_name));
+        let mut pseudo_elements = String::new();
+
+        for state in self.states.iter().rev() {
+            match state.variant().pseudo_element() {
+                Some(pe) => pseudo_elements.push_str(pe),
+                None => selector = state.variant().apply_selector(&selector),
+            }
+        }
+
+        selector.push_str(&pseudo_elements);
+
+        if let Some(to_append) = selector_to_append {
+            selector = format!("{} {}", selector, to_append);
+        }
+
+        let mut nodes = self.class.into_nodes(Selector::new(selector), config);
+
+        // At-rules are wrapped in the order of their variants, with the screens around everything
+        // else, so every class with the same at-rules ends up with the same at-rule chain,
+        // no matter how its states were written. An arbitrary variant has no order of its own,
+        // so it goes right outside the variant written after it, or inside everything if it's last.
+        let mut states: Vec<(usize, &State)> = self.states.iter().enumerate().collect();
+        states.sort_by_key(|(i, state)| {
+            if !state.is_arbitrary() {
+                return (Some(state.order()), 0, Reverse(0));
+            }
+
+            let inner = self.states[i + 1..].iter().find(|s| !s.is_arbitrary());
+            (inner.map(State::order), 1, Reverse(*i))
+        });
+        let states: Vec<&State> = states.into_iter().map(|(_, state)| state).collect();
+
+        // Breakpoints are combined into one media query where the last of them goes,
+        // so `md:max-xl:` is a single range
+        let conditions: Vec<String> = states
+            .iter()
+            .rev()
+            .filter_map(|s| s.variant().breakpoint_condition())
+            .collect();
+        let last_breakpoint = states
+            .iter()
+            .rposition(|s| s.variant().breakpoint_condition().is_some());
+
+        for (i, state) in states.into_iter().enumerate() {
+            if state.variant().breakpoint_condition().is_none() || conditions.len() == 1 {
+                nodes = state.variant().wrap(nodes);
+            } else if Some(i) == last_breakpoint {
+                nodes = Variant::at_rule("media", conditions.join(" and ")).wrap(nodes);
+            }
+        }
+
+        Ok(nodes)
+    }
+}
+
+pub struct SourceOptions<'a> {
+    pub input: &'a PathBuf,
+    pub option: CollectionOptions,
+}
+
+pub enum Source<'a> {
+    File(SourceOptions<'a>),
+    Files(Vec<SourceOptions<'a>>),
+    String(String, CollectionOptions),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CollectionOptions {
+    Html,
+    String,
+    #[serde(with = "serde_regex")]
+    Regex(Regex),
+}
+
+impl CollectionOptions {
+    pub fn new(value: &str, expand: Option<HashMap<String, CollectionOptions>>) -> Self {
+        if let Some(exp) = expand {
+            if let Some(opt) = exp.get(value) {
+                return opt.clone();
+            }
+        }
+
+        match value {
+            "html" => CollectionOptions::Html,
+            _ => CollectionOptions::String,
+        }
+    }
+}
+
+/// A convenience function to write a `Source` to a `railwind` CSS file
+pub fn parse_to_file(
+    source: Source,
+    output: &str,
+    include_preflight: bool,
+    warnings: &mut Vec<Warning>,
+) {
+    let css = parse_to_string(source, include_preflight, warnings);
+
+    let mut file = File::create(output).unwrap();
+    file.write_all(css.as_bytes()).unwrap();
+}
+
+/// Parses a source to a `railwind` CSS string with the default config.
+/// Use a `Compiler` to compile many sources with the same config
+pub fn parse_to_string(
+    source: Source,
+    include_preflight: bool,
+    warnings: &mut Vec<Warning>,
+) -> String {
+    Compiler::default()
+        .include_preflight(include_preflight)
+        .compile(source, warnings)
+}
+
+fn collect_with_option<'a>(
+    str: &'a str,
+    option: &CollectionOptions,
+) -> IndexMap<&'a str, Position> {
+    match option {
+        CollectionOptions::Html => collect_from_html(str),
+        CollectionOptions::String => collect(str),
+        CollectionOptions::Regex(r) => collect_with_regex(str, r),
+    }
+}
+
+fn collect_from_html(str: &str) -> IndexMap<&str, Position> {
+    let lookup = LineColLookup::new(str);
+    let mut raw_classes = IndexMap::new();
+
+    for cap in parser::class_attrs(str) {
+        let index = cap.as_ptr() as usize - str.as_ptr() as usize;
+        let position: Position = lookup.get(index).into();
+        raw_classes.entry(cap).or_insert(position);
+    }
+
+    raw_classes
+}
+
+fn collect_with_regex<'a>(str: &'a str, regex: &Regex) -> IndexMap<&'a str, Position> {
+    let lookup = LineColLookup::new(str);
+
+    let mut raw_classes = IndexMap::new();
+
+    for captures in regex.captures_iter(str) {
+        if let Some(group) = captures.get(1) {
+            let mut index = group.start();
+
+            for cap in group.as_str().split([' ', '\n']) {
+                if cap.is_empty() {
+                    index += cap.len() + 1;
+                    continue;
+                }
+
+                let position: Position = lookup.get(index).into();
+                raw_classes.entry(cap).or_insert(position);
+
+                index += cap.len() + 1;
+            }
+        }
+    }
+
+    raw_classes
+}
+
+fn collect(str: &str) -> IndexMap<&str, Position> {
+    let lookup = LineColLookup::new(str);
+    let mut classes = IndexMap::new();
+    let mut index = 0;
+
+    for cap in str.split([' ', '\n']) {
+        if cap.is_empty() {
+            index += cap.len() + 1;
+            continue;
+        }
+
+        classes
+            .entry(cap)
+            .or_insert_with(|| lookup.get(index).into());
+
+        index += cap.len() + 1;
+    }
+
+    classes
+}
+
+/// Generates the nodes of every class, sorted like Tailwind sorts them
+fn generate_nodes(
+    raw_classes: IndexMap<&str, Position>,
+    config: &Config,
+    warnings: &mut Vec<Warning>,
+) -> Vec<Node> {
+    let mut out = vec![];
+
+    for (raw_class, position) in raw_classes {
+        let normalized = normalize_arbitrary_values(raw_class);
+
+        let generated = ParsedClass::new_from_raw_class(raw_class, &normalized, position, config)
+            .and_then(|class| {
+                let pos = class.position.clone();
+                let key = SortKey::new(&class);
+                class
+                    .try_into_nodes(config)
+                    .map(|mut nodes| {
+                        for node in nodes.iter_mut() {
+                            node.set_source(&pos);
+                        }
+                        (key.with_properties(&nodes), nodes)
+                    })
+                    .map_err(|w| Warning::new(raw_class, &pos, w))
+            });
+
+        match generated {
+            Ok(c) => out.push(c),
+            Err(w) => warnings.push(w),
+        }
+    }
+
+    out.sort_by(|(a, _), (b, _)| a.cmp(b));
+    merge_at_rules(out.into_iter().flat_map(|(_, nodes)| nodes).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::Printer;
+
+    // Compiles the classes without any whitespace, so whole selectors and at-rules can be compared
+    fn minified(classes: &str) -> String {
+        let mut warnings = vec![];
+        let css = Compiler::default()
+            .printer(Printer::minified())
+            .compile_str(classes, &CollectionOptions::String, &mut warnings);
+
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        css
+    }
+
+    #[test]
+    fn test_collect_classes_from_str() {
+        let text = "px-5 justify-start container";
+        let classes = collect(text);
+
+        assert!(!classes.is_empty());
+        assert_eq!(
+            classes,
+            IndexMap::from([
+                ("px-5", Position::new("", 1, 1)),
+                ("justify-start", Position::new("", 1, 6)),
+                ("container", Position::new("", 1, 20))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_classes_fail() {
+        let text = "space-c-4";
+        let mut warnings = vec![];
+        let _ = parse_to_string(
+            Source::String(text.into(), CollectionOptions::String),
+            false,
+            &mut warnings,
+        );
+
+        assert!(!warnings.is_empty())
+    }
+
+    #[test]
+    fn test_collect_classes_from_html() {
+        let text = r#"class="px-5 justify-start container""#;
+        let classes = collect_from_html(text);
+
+        assert!(!classes.is_empty());
+        assert_eq!(
+            classes,
+            IndexMap::from([
+                ("px-5", Position::new("", 1, 8)),
+                ("justify-start", Position::new("", 1, 13)),
+                ("container", Position::new("", 1, 27))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_stacked_variants() {
+        assert_eq!(
+            minified("before:hover:p-1"),
+            r".before\:hover\:p-1:hover::before{padding:.25rem}"
+        );
+        assert_eq!(
+            minified("group-hover:peer-focus:p-2"),
+            r".group:hover .peer:focus~.group-hover\:peer-focus\:p-2{padding:.5rem}"
+        );
+        assert_eq!(
+            minified("[&_p]:hover:p-3"),
+            r".\[\&_p\]\:hover\:p-3:hover p{padding:.75rem}"
+        );
+        assert_eq!(
+            minified("[.dark_&]:hover:flex"),
+            r".dark .\[\.dark_\&\]\:hover\:flex:hover{display:flex}"
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_are_combined() {
+        assert_eq!(
+            minified("md:max-xl:flex max-xl:md:block"),
+            r"@media (min-width:768px) and (not (min-width:1280px)){.max-xl\:md\:block{display:block}.md\:max-xl\:flex{display:flex}}"
+        );
+        assert_eq!(
+            minified("max-md:hidden min-[900px]:p-4"),
+            r"@media not all and (min-width:768px){.max-md\:hidden{display:none}}@media (min-width:900px){.min-\[900px\]\:p-4{padding:1rem}}"
+        );
+    }
+
+    #[test]
+    fn test_arbitrary_at_rules_are_nested_where_written() {
+        assert_eq!(
+            minified("[@supports(display:grid)]:md:p-1"),
+            r"@supports (display:grid){@media (min-width:768px){.\[\@supports\(display\:grid\)\]\:md\:p-1{padding:.25rem}}}"
+        );
+        assert_eq!(
+            minified("md:[@supports(display:grid)]:p-2"),
+            r"@media (min-width:768px){@supports (display:grid){.md\:\[\@supports\(display\:grid\)\]\:p-2{padding:.5rem}}}"
+        );
+    }
+
+    #[test]
+    fn test_group_and_peer_markers() {
+        assert_eq!(minified("group group/sidebar peer/terms"), "");
+        assert_eq!(
+            minified("group group/sidebar group-hover/sidebar:p-1 peer-checked/terms:p-2"),
+            r".group\/sidebar:hover .group-hover\/sidebar\:p-1{padding:.25rem}.peer\/terms:checked~.peer-checked\/terms\:p-2{padding:.5rem}"
+        );
+    }
+
+    #[test]
+    fn test_collection_options() {
+        let opts = CollectionOptions::new("html", None);
+
+        assert!(matches!(opts, CollectionOptions::Html));
+
+        let opts = CollectionOptions::new(
+            "rs",
+            Some(HashMap::from([("rs".to_string(), CollectionOptions::Html)])),
+        );
+
+        assert!(matches!(opts, CollectionOptions::Html));
+    }
+}
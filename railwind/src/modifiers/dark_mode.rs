@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use super::Variant;
+
+/// How the `dark:` variant decides the dark theme is on
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DarkMode {
+    /// `@media (prefers-color-scheme: dark)`, following the operating system
+    #[default]
+    Media,
+    /// When an ancestor has the `dark` class, like `.dark .dark\:flex`
+    Class,
+    /// When an ancestor matches the selector, like `[data-theme="dark"]`.
+    /// A selector with an `&` is used as it is, like `&:where(.dark, .dark *)`.
+    Selector(String),
+}
+
+impl DarkMode {
+    pub fn variant(&self) -> Variant {
+        match self {
+            Self::Media => Variant::at_rule("media", "(prefers-color-scheme: dark)"),
+            Self::Class => Variant::selector(".dark &"),
+            Self::Selector(selector) if selector.contains('&') => Variant::selector(selector),
+            Self::Selector(selector) => Variant::selector(format!("{} &", selector)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dark_mode_variant() {
+        assert_eq!(
+            DarkMode::Media.variant(),
+            Variant::at_rule("media", "(prefers-color-scheme: dark)")
+        );
+        assert_eq!(DarkMode::Class.variant(), Variant::selector(".dark &"));
+        assert_eq!(
+            DarkMode::Selector("[data-theme=\"dark\"]".into()).variant(),
+            Variant::selector("[data-theme=\"dark\"] &")
+        );
+        assert_eq!(
+            DarkMode::Selector("&:where(.dark, .dark *)".into()).variant(),
+            Variant::selector("&:where(.dark, .dark *)")
+        );
+    }
+}
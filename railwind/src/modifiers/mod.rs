@@ -1,120 +1,67 @@
-mod group;
-mod media_query;
-mod peer;
-mod pseudo_class;
-mod pseudo_element;
+mod dark_mode;
+mod registry;
+mod screen;
+mod variant;
 
-pub use group::Group;
-pub use media_query::MediaQuery;
-pub use peer::Peer;
-pub use pseudo_class::PseudoClass;
-pub use pseudo_element::PseudoElement;
+pub use dark_mode::DarkMode;
+pub use registry::{VariantPlugin, Variants};
+pub use screen::{Screen, Screens};
+pub use variant::Variant;
 
 use crate::warning::{Position, Warning, WarningType};
 
+/// A variant a class was prefixed with, like the `hover` in `hover:underline`
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
-pub enum State {
-    PseudoClass(PseudoClass),
-    PseudoElement(PseudoElement),
-    MediaQuery(MediaQuery),
-    Group(Group),
-    Peer(Peer),
+pub struct State {
+    variant: Variant,
+    order: (usize, usize),
+    arbitrary: bool,
 }
 
 impl State {
-    pub fn new(raw_class: &str, value: &str, position: &Position) -> Result<Self, Warning> {
-        if let Some(mq) = MediaQuery::new(value) {
-            return Ok(Self::MediaQuery(mq));
+    pub fn new(
+        raw_class: &str,
+        value: &str,
+        position: &Position,
+        variants: &Variants,
+    ) -> Result<Self, Warning> {
+        if let Some((variant, order)) = variants.find(value) {
+            return Ok(Self {
+                variant,
+                order,
+                arbitrary: false,
+            });
         }
 
-        if let Some(pc) = PseudoClass::new(value) {
-            return Ok(Self::PseudoClass(pc));
+        // Like in Tailwind, arbitrary variants come after all the registered ones
+        if let Some(variant) = Variant::arbitrary(value) {
+            return Ok(Self {
+                variant,
+                order: (usize::MAX, 0),
+                arbitrary: true,
+            });
         }
 
-        if let Some(pe) = PseudoElement::new(value) {
-            return Ok(Self::PseudoElement(pe));
-        }
-
-        if let Some(g) = Group::new(value) {
-            return Ok(Self::Group(g));
-        }
-
-        if let Some(p) = Peer::new(value) {
-            return Ok(Self::Peer(p));
-        }
+        // A plugin can tell why one of its variants didn't match, like `nth-[2n+]`
+        let warning_type = variants
+            .warning(value)
+            .unwrap_or_else(|| WarningType::StateNotFound(value.to_string()));
 
-        Err(Warning::new(
-            raw_class,
-            &position,
-            WarningType::StateNotFound(value.to_string()),
-        ))
+        Err(Warning::new(raw_class, position, warning_type))
     }
-}
-
-pub fn generate_state_selector(states: Vec<State>) -> String {
-    let mut pseudo_classes = vec![];
-    let mut pseudo_elements = vec![];
 
-    for state in states {
-        match state {
-            State::PseudoClass(pc) => pseudo_classes.push(pc.to_static_str()),
-            State::PseudoElement(pe) => pseudo_elements.push(pe.to_static_str()),
-            _ => (),
-        }
+    pub fn variant(&self) -> &Variant {
+        &self.variant
     }
 
-    let pc = pseudo_classes.join(":");
-    let pe = pseudo_elements.join("::");
-
-    if pe.is_empty() {
-        return pc;
+    /// Where rules with this state go in the output,
+    /// which follows the order the variants were registered in
+    pub fn order(&self) -> (usize, usize) {
+        self.order
     }
 
-    if pc.is_empty() {
-        return format!(":{}", pe);
-    }
-
-    format!("{}::{}", pc, pe)
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::modifiers::{PseudoClass, PseudoElement};
-
-    use super::*;
-
-    #[test]
-    fn test_generate_state_selector() {
-        let states = vec![State::PseudoClass(PseudoClass::Active)];
-        assert_eq!(generate_state_selector(states), "active".to_string());
-
-        let states = vec![
-            State::PseudoClass(PseudoClass::Active),
-            State::PseudoClass(PseudoClass::Hover),
-        ];
-        assert_eq!(generate_state_selector(states), "active:hover".to_string());
-
-        let states = vec![State::PseudoElement(PseudoElement::Before)];
-        assert_eq!(generate_state_selector(states), ":before".to_string());
-
-        let states = vec![
-            State::PseudoElement(PseudoElement::Before),
-            State::PseudoElement(PseudoElement::After),
-        ];
-        assert_eq!(
-            generate_state_selector(states),
-            ":before::after".to_string()
-        );
-
-        let states = vec![
-            State::PseudoClass(PseudoClass::Active),
-            State::PseudoElement(PseudoElement::Before),
-            State::PseudoClass(PseudoClass::Hover),
-            State::PseudoElement(PseudoElement::After),
-        ];
-        assert_eq!(
-            generate_state_selector(states),
-            "active:hover::before::after".to_string()
-        );
+    /// Whether the state is a one-off variant in square brackets, like `[@supports(display:grid)]`
+    pub fn is_arbitrary(&self) -> bool {
+        self.arbitrary
     }
 }
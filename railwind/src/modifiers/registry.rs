@@ -0,0 +1,1141 @@
+use std::collections::HashMap;
+
+use super::{DarkMode, Screen, Screens, Variant};
+use crate::utils::replace_invalid_chars;
+use crate::warning::WarningType;
+
+/// Variants whose names are only known once they are used, like `group-hover`,
+/// which is made out of the `hover` variant. Implement it to add a whole family of variants.
+pub trait VariantPlugin: Send {
+    /// The variant called `name`, if the plugin knows it. `variants` is the registry
+    /// the plugin was added to, so it can build on the other variants.
+    fn variant(&self, name: &str, variants: &Variants) -> Option<Variant>;
+
+    /// Orders the variants of the plugin among each other, lower comes first
+    fn order(&self, _name: &str, _variants: &Variants) -> usize {
+        0
+    }
+
+    /// Why `name` isn't a variant, if it's one of the variants of the plugin written wrong,
+    /// like `nth-[2n+]`. It's only asked about names that no variant matched.
+    fn warning(&self, _name: &str, _variants: &Variants) -> Option<WarningType> {
+        None
+    }
+}
+
+enum Entry {
+    Named(String, Variant),
+    Plugin(Box<dyn VariantPlugin>),
+}
+
+/// Every variant a class can be prefixed with, like `hover:` or `md:`.
+/// Rules are sorted in the order the variants were added in, so a rule with a variant
+/// that was added later wins over a rule with one that was added earlier.
+pub struct Variants {
+    entries: Vec<Entry>,
+    screens: Screens,
+    containers: HashMap<String, String>,
+    aria: HashMap<String, String>,
+    data: HashMap<String, String>,
+    supports: HashMap<String, String>,
+}
+
+// Tailwind's order of the pseudo elements and pseudo classes,
+// so `disabled:` is emitted after `hover:` and wins
+const PSEUDO_ELEMENTS: [(&str, &str); 10] = [
+    ("first-letter", "first-letter"),
+    ("first-line", "first-line"),
+    ("last-line", "last-line"),
+    ("marker", "marker"),
+    ("selection", "selection"),
+    ("file", "file-selector-button"),
+    ("placeholder", "placeholder"),
+    ("backdrop", "backdrop"),
+    ("before", "before"),
+    ("after", "after"),
+];
+
+const PSEUDO_CLASSES: [(&str, &str); 30] = [
+    ("first", "first-child"),
+    ("last", "last-child"),
+    ("only", "only-child"),
+    ("odd", "nth-child(odd)"),
+    ("even", "nth-child(even)"),
+    ("first-of-type", "first-of-type"),
+    ("last-of-type", "last-of-type"),
+    ("only-of-type", "only-of-type"),
+    ("visited", "visited"),
+    ("target", "target"),
+    ("open", "open"),
+    ("default", "default"),
+    ("checked", "checked"),
+    ("indeterminate", "indeterminate"),
+    ("placeholder-shown", "placeholder-shown"),
+    ("autofill", "autofill"),
+    ("required", "required"),
+    ("valid", "valid"),
+    ("invalid", "invalid"),
+    ("in-range", "in-range"),
+    ("out-of-range", "out-of-range"),
+    ("readonly", "readonly"),
+    ("empty", "empty"),
+    ("focus-within", "focus-within"),
+    ("hover", "hover"),
+    ("focus", "focus"),
+    ("focus-visible", "focus-visible"),
+    ("active", "active"),
+    ("enabled", "enabled"),
+    ("disabled", "disabled"),
+];
+
+// A name is read with the longest prefix it has, so `nth-last-2` isn't `nth` with `last-2`
+const NTH: [(&str, &str); 4] = [
+    ("nth", "nth-child"),
+    ("nth-last", "nth-last-child"),
+    ("nth-of-type", "nth-of-type"),
+    ("nth-last-of-type", "nth-last-of-type"),
+];
+
+const PREFERENCES: [(&str, &str); 4] = [
+    ("motion-safe", "(prefers-reduced-motion: no-preference)"),
+    ("motion-reduce", "(prefers-reduced-motion: reduce)"),
+    ("contrast-more", "(prefers-contrast: more)"),
+    ("contrast-less", "(prefers-contrast: less)"),
+];
+
+// Tailwind's ARIA attributes that are either `true` or `false`
+const ARIA: [&str; 9] = [
+    "busy", "checked", "disabled", "expanded", "hidden", "pressed", "readonly", "required",
+    "selected",
+];
+
+const SCREENS: [(&str, &str); 5] = [
+    ("sm", "640px"),
+    ("md", "768px"),
+    ("lg", "1024px"),
+    ("xl", "1280px"),
+    ("2xl", "1536px"),
+];
+
+const CONTAINERS: [(&str, &str); 11] = [
+    ("xs", "20rem"),
+    ("sm", "24rem"),
+    ("md", "28rem"),
+    ("lg", "32rem"),
+    ("xl", "36rem"),
+    ("2xl", "42rem"),
+    ("3xl", "48rem"),
+    ("4xl", "56rem"),
+    ("5xl", "64rem"),
+    ("6xl", "72rem"),
+    ("7xl", "80rem"),
+];
+
+impl Default for Variants {
+    fn default() -> Self {
+        let mut variants = Self::empty();
+
+        variants.add("*", Variant::selector("& > *"));
+
+        for (name, pe) in PSEUDO_ELEMENTS {
+            variants.add(name, Variant::selector(format!("&::{}", pe)));
+        }
+
+        for (name, pc) in PSEUDO_CLASSES {
+            variants.add(name, Variant::selector(format!("&:{}", pc)));
+        }
+
+        variants.add_plugin(Nth);
+
+        variants.add_plugin(PseudoClassOf {
+            marker: "group",
+            combinator: " ",
+        });
+        variants.add_plugin(PseudoClassOf {
+            marker: "peer",
+            combinator: " ~ ",
+        });
+
+        variants.aria = ARIA
+            .iter()
+            .map(|name| (name.to_string(), format!("{}=\"true\"", name)))
+            .collect();
+        variants.add_plugin(Attribute {
+            prefix: "aria",
+            table: Variants::aria,
+        });
+        variants.add_plugin(Attribute {
+            prefix: "data",
+            table: Variants::data,
+        });
+        variants.add_plugin(Supports);
+
+        variants.add_plugin(Relational {
+            prefix: "has",
+            selector: |condition| format!("&:has({})", condition),
+            other_element: true,
+        });
+        variants.add_plugin(Relational {
+            prefix: "not",
+            selector: |condition| format!("&:not({})", condition),
+            other_element: false,
+        });
+        variants.add_plugin(Relational {
+            prefix: "in",
+            selector: |condition| format!(":where({}) &", condition),
+            other_element: true,
+        });
+
+        // The direction is inherited, so it can be set on the element or any of its ancestors
+        for dir in ["ltr", "rtl"] {
+            variants.add(
+                dir,
+                Variant::selector(format!("&:where([dir=\"{0}\"], [dir=\"{0}\"] *)", dir)),
+            );
+        }
+
+        for (name, params) in PREFERENCES {
+            variants.add(name, Variant::at_rule("media", params));
+        }
+
+        variants.add("dark", DarkMode::default().variant());
+
+        variants.add("print", Variant::at_rule("media", "print"));
+
+        variants.add_plugin(MaxWidth);
+        variants.set_screens(
+            SCREENS
+                .iter()
+                .map(|(name, min)| (name.to_string(), Screen::Min(min.to_string())))
+                .collect(),
+        );
+        variants.add_plugin(MinWidth);
+
+        variants.add(
+            "portrait",
+            Variant::at_rule("media", "(orientation: portrait)"),
+        );
+        variants.add(
+            "landscape",
+            Variant::at_rule("media", "(orientation: landscape)"),
+        );
+
+        variants.containers = CONTAINERS
+            .iter()
+            .map(|(name, min)| (name.to_string(), min.to_string()))
+            .collect();
+        variants.add_plugin(ContainerQuery);
+
+        variants
+    }
+}
+
+impl Variants {
+    /// A registry without any variants, not even the built in ones
+    pub fn empty() -> Self {
+        Self {
+            entries: vec![],
+            screens: Screens::new(),
+            containers: HashMap::new(),
+            aria: HashMap::new(),
+            data: HashMap::new(),
+            supports: HashMap::new(),
+        }
+    }
+
+    /// Adds a variant after all the others. A variant with
+    /// the same name is replaced instead, but keeps its place in the order.
+    pub fn add(&mut self, name: impl Into<String>, variant: Variant) {
+        let name = name.into();
+
+        let existing = self.entries.iter_mut().find_map(|entry| match entry {
+            Entry::Named(n, v) if *n == name => Some(v),
+            _ => None,
+        });
+
+        match existing {
+            Some(v) => *v = variant,
+            None => self.entries.push(Entry::Named(name, variant)),
+        }
+    }
+
+    /// Adds a family of variants after all the others
+    pub fn add_plugin(&mut self, plugin: impl VariantPlugin + 'static) {
+        self.entries.push(Entry::Plugin(Box::new(plugin)));
+    }
+
+    pub fn screens(&self) -> &Screens {
+        &self.screens
+    }
+
+    /// Replaces the responsive variants, like `md`. The new ones take the place of the old ones
+    /// in the order, or are added after all the others if there weren't any.
+    pub fn set_screens(&mut self, screens: Screens) {
+        let is_screen = |entry: &Entry| matches!(entry, Entry::Named(n, _) if self.screens.contains_key(n) || screens.contains_key(n));
+
+        // Nothing before the first screen is removed, so it's still the right place afterwards
+        let position = self
+            .entries
+            .iter()
+            .position(is_screen)
+            .unwrap_or(self.entries.len());
+        self.entries.retain(|entry| !is_screen(entry));
+
+        let new = screens
+            .iter()
+            .map(|(name, screen)| Entry::Named(name.clone(), screen.variant()));
+        self.entries.splice(position..position, new);
+
+        self.screens = screens;
+    }
+
+    /// The sizes of the container query variants, like `@md`
+    pub fn containers(&self) -> &HashMap<String, String> {
+        &self.containers
+    }
+
+    pub fn containers_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.containers
+    }
+
+    /// The attributes of the `aria-*` variants, like `checked="true"` for `aria-checked`
+    pub fn aria(&self) -> &HashMap<String, String> {
+        &self.aria
+    }
+
+    pub fn aria_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.aria
+    }
+
+    /// The attributes of the `data-*` variants, like `state=open` for `data-open`
+    pub fn data(&self) -> &HashMap<String, String> {
+        &self.data
+    }
+
+    pub fn data_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.data
+    }
+
+    /// The conditions of the `supports-*` variants, like `display: grid` for `supports-grid`
+    pub fn supports(&self) -> &HashMap<String, String> {
+        &self.supports
+    }
+
+    pub fn supports_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.supports
+    }
+
+    /// Changes what the `dark` variant does, which keeps its place in the order
+    pub fn set_dark_mode(&mut self, dark_mode: &DarkMode) {
+        self.add("dark", dark_mode.variant());
+    }
+
+    pub fn get(&self, name: &str) -> Option<Variant> {
+        self.find(name).map(|(variant, _)| variant)
+    }
+
+    /// The variant called `name` and where its rules go in the output
+    pub(crate) fn find(&self, name: &str) -> Option<(Variant, (usize, usize))> {
+        for (index, entry) in self.entries.iter().enumerate() {
+            match entry {
+                Entry::Named(n, variant) if n == name => {
+                    return Some((variant.clone(), (index, 0)))
+                }
+                Entry::Plugin(plugin) => {
+                    if let Some(variant) = plugin.variant(name, self) {
+                        return Some((variant, (index, plugin.order(name, self))));
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        None
+    }
+
+    /// Why the variant called `name` doesn't exist, if a plugin knows
+    pub(crate) fn warning(&self, name: &str) -> Option<WarningType> {
+        self.entries.iter().find_map(|entry| match entry {
+            Entry::Plugin(plugin) => plugin.warning(name, self),
+            Entry::Named(..) => None,
+        })
+    }
+}
+
+/// Makes a variant out of every variant that only adds a pseudo class or an attribute to the
+/// selector, like `group-hover` out of `hover`, for when it matches the `marker` element
+struct PseudoClassOf {
+    marker: &'static str,
+    combinator: &'static str,
+}
+
+impl PseudoClassOf {
+    /// The variant after the marker and the name of the group or peer,
+    /// like `hover` and `sidebar` in `group-hover/sidebar`
+    fn split<'a>(&self, name: &'a str) -> Option<(&'a str, Option<&'a str>)> {
+        let rest = name.strip_prefix(self.marker)?.strip_prefix('-')?;
+
+        // The slash can't be inside the brackets of an arbitrary variant
+        match rest.rsplit_once('/') {
+            Some((variant, marker_name))
+                if !marker_name.is_empty() && !marker_name.contains(']') =>
+            {
+                Some((variant, Some(marker_name)))
+            }
+            _ => Some((rest, None)),
+        }
+    }
+
+    /// The class of the marked element, like `.group` or `.group\/sidebar`
+    fn marker_selector(&self, marker_name: Option<&str>) -> String {
+        match marker_name {
+            Some(marker_name) => format!(
+                ".{}",
+                replace_invalid_chars(format!("{}/{}", self.marker, marker_name))
+            ),
+            None => format!(".{}", self.marker),
+        }
+    }
+}
+
+impl VariantPlugin for PseudoClassOf {
+    fn variant(&self, name: &str, variants: &Variants) -> Option<Variant> {
+        let (variant, marker_name) = self.split(name)?;
+        let marker = self.marker_selector(marker_name);
+
+        // An arbitrary variant, like `group-[.is-open]`, is added to the marker,
+        // unless it has an `&` to say where the marker goes, like `group-[.sidebar_&]`
+        let condition = match arbitrary_value(variant) {
+            Some(selector) if selector.contains('&') => selector.replace('&', &marker),
+            Some(selector) => format!("{}{}", marker, selector),
+            None => format!("{}{}", marker, variants.get(variant)?.compound_selector()?),
+        };
+
+        Some(Variant::selector(format!(
+            "{}{}&",
+            condition, self.combinator
+        )))
+    }
+
+    // Arbitrary variants come after the ones made out of the other variants
+    fn order(&self, name: &str, variants: &Variants) -> usize {
+        match self.split(name) {
+            Some((variant, _)) if arbitrary_value(variant).is_some() => usize::MAX,
+            Some((variant, _)) => variants.find(variant).map_or(0, |(_, (index, _))| index),
+            None => 0,
+        }
+    }
+    fn warning(&self, name: &str, variants: &Variants) -> Option<WarningType> {
+        variants.warning(self.split(name)?.0)
+    }
+}
+
+/// `nth-3` for every positive number, and the arbitrary `nth-[3n+1]`, which apply when the
+/// position of the element matches, with `nth-last-*`, `nth-of-type-*` and `nth-last-of-type-*`
+/// in the same way. Anything in the brackets that isn't an `An+B`, like `nth-[3x]`, is warned about.
+struct Nth;
+
+impl Nth {
+    /// The place in `NTH`, the pseudo class and the value of the variant, like `nth-last-child`
+    /// and `2` for `nth-last-2`
+    fn split<'a>(&self, name: &'a str) -> Option<(usize, &'static str, &'a str)> {
+        NTH.iter()
+            .enumerate()
+            .filter_map(|(index, (prefix, pseudo_class))| {
+                let value = name.strip_prefix(prefix)?.strip_prefix('-')?;
+                Some((prefix.len(), (index, *pseudo_class, value)))
+            })
+            .max_by_key(|(prefix_len, _)| *prefix_len)
+            .map(|(_, split)| split)
+    }
+}
+
+impl VariantPlugin for Nth {
+    fn variant(&self, name: &str, _: &Variants) -> Option<Variant> {
+        let (_, pseudo_class, value) = self.split(name)?;
+
+        let position = match arbitrary_value(value) {
+            Some(position) if is_an_plus_b(position) => position.trim(),
+            Some(_) => return None,
+            None if is_unsigned_integer(value) => value,
+            None => return None,
+        };
+
+        Some(Variant::selector(format!(
+            "&:{}({})",
+            pseudo_class, position
+        )))
+    }
+
+    fn order(&self, name: &str, _: &Variants) -> usize {
+        self.split(name).map_or(0, |(index, _, _)| index)
+    }
+
+    fn warning(&self, name: &str, _: &Variants) -> Option<WarningType> {
+        let (_, _, value) = self.split(name)?;
+
+        Some(WarningType::InvalidStateArg(
+            name.to_string(),
+            arbitrary_value(value).unwrap_or(value).to_string(),
+            "an An+B expression, like '3', '2n+1' or 'odd'".to_string(),
+        ))
+    }
+}
+
+/// `has-*`, `not-*` and `in-*`, which check a condition on a descendant, on the element itself
+/// or on an ancestor. The condition is another variant, like `checked` in `has-checked`,
+/// or written in square brackets, like `has-[img]`.
+struct Relational {
+    prefix: &'static str,
+    /// The selector of the variant, made out of the condition, like `&:has(*:checked)`
+    selector: fn(&str) -> String,
+    /// Whether the condition is on another element than the one with the class,
+    /// so `has-checked` checks for `*:checked` instead of `:checked`
+    other_element: bool,
+}
+
+impl Relational {
+    fn value<'a>(&self, name: &'a str) -> Option<&'a str> {
+        name.strip_prefix(self.prefix)?.strip_prefix('-')
+    }
+}
+
+impl VariantPlugin for Relational {
+    fn variant(&self, name: &str, variants: &Variants) -> Option<Variant> {
+        let value = self.value(name)?;
+
+        let condition = match arbitrary_value(value) {
+            Some(condition) => condition.to_string(),
+            None => {
+                let variant = variants.get(value)?;
+                let compound = variant.compound_selector()?;
+
+                if self.other_element {
+                    format!("*{}", compound)
+                } else {
+                    compound.to_string()
+                }
+            }
+        };
+
+        Some(Variant::selector((self.selector)(&condition)))
+    }
+
+    // Arbitrary variants come after the ones made out of the other variants
+    fn order(&self, name: &str, variants: &Variants) -> usize {
+        match self.value(name) {
+            Some(value) if arbitrary_value(value).is_some() => usize::MAX,
+            Some(value) => variants.find(value).map_or(0, |(_, (index, _))| index),
+            None => 0,
+        }
+    }
+    fn warning(&self, name: &str, variants: &Variants) -> Option<WarningType> {
+        variants.warning(self.value(name)?)
+    }
+}
+
+/// `aria-checked` for every attribute in the `table`, and the arbitrary `aria-[sort=ascending]`,
+/// which apply when the element has the attribute with the `prefix`
+struct Attribute {
+    prefix: &'static str,
+    table: fn(&Variants) -> &HashMap<String, String>,
+}
+
+impl VariantPlugin for Attribute {
+    fn variant(&self, name: &str, variants: &Variants) -> Option<Variant> {
+        let value = name.strip_prefix(self.prefix)?.strip_prefix('-')?;
+
+        let attribute = match arbitrary_value(value) {
+            Some(attribute) => attribute,
+            None => (self.table)(variants).get(value)?,
+        };
+
+        Some(Variant::selector(format!(
+            "&[{}-{}]",
+            self.prefix, attribute
+        )))
+    }
+}
+
+/// `supports-grid` for every condition in the `supports` table, and the arbitrary
+/// `supports-[display:grid]`. A condition without a value, like `supports-[backdrop-filter]`,
+/// checks that the property is supported.
+struct Supports;
+
+impl VariantPlugin for Supports {
+    fn variant(&self, name: &str, variants: &Variants) -> Option<Variant> {
+        let value = name.strip_prefix("supports-")?;
+
+        let condition = match arbitrary_value(value) {
+            Some(condition) => condition,
+            None => variants.supports().get(value)?,
+        };
+
+        let condition = if condition.contains(':') || condition.starts_with('(') {
+            condition.to_string()
+        } else {
+            format!("{}: var(--tw)", condition)
+        };
+
+        let params = if condition.starts_with('(') && condition.ends_with(')') {
+            condition
+        } else {
+            format!("({})", condition)
+        };
+
+        Some(Variant::at_rule("supports", params))
+    }
+}
+
+/// `max-md` for every screen with only a minimum width, and the arbitrary `max-[900px]`, which
+/// are everything below the width. They come before the screens, the widest first, so the
+/// narrower ones win.
+struct MaxWidth;
+
+impl MaxWidth {
+    fn width<'a>(&self, name: &'a str, variants: &'a Variants) -> Option<&'a str> {
+        let value = name.strip_prefix("max-")?;
+
+        match arbitrary_value(value) {
+            Some(width) => Some(width),
+            None => match variants.screens().get(value)? {
+                Screen::Min(min) => Some(min),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl VariantPlugin for MaxWidth {
+    fn variant(&self, name: &str, variants: &Variants) -> Option<Variant> {
+        let width = self.width(name, variants)?;
+        Some(Variant::at_rule(
+            "media",
+            format!("not all and (min-width: {})", width),
+        ))
+    }
+
+    fn order(&self, name: &str, variants: &Variants) -> usize {
+        usize::MAX - self.width(name, variants).map_or(0, width_order)
+    }
+}
+
+/// The arbitrary `min-[900px]`, which comes after the screens, the narrowest first
+struct MinWidth;
+
+impl VariantPlugin for MinWidth {
+    fn variant(&self, name: &str, _: &Variants) -> Option<Variant> {
+        let width = arbitrary_value(name.strip_prefix("min-")?)?;
+        Some(Variant::at_rule("media", format!("(min-width: {})", width)))
+    }
+
+    fn order(&self, name: &str, _: &Variants) -> usize {
+        name.strip_prefix("min-")
+            .and_then(arbitrary_value)
+            .map_or(0, width_order)
+    }
+}
+
+/// `@md` for every size in the containers, and the arbitrary `@[400px]`, which apply when the
+/// nearest container is at least that wide. A name after a slash, like `@md/sidebar`,
+/// looks at the container with that name instead. The narrowest come first.
+struct ContainerQuery;
+
+impl ContainerQuery {
+    fn width_and_name<'a>(
+        &self,
+        name: &'a str,
+        variants: &'a Variants,
+    ) -> Option<(&'a str, Option<&'a str>)> {
+        let query = name.strip_prefix('@')?;
+
+        // The slash can't be inside the brackets of an arbitrary size
+        let (size, container) = match query.rsplit_once('/') {
+            Some((size, container)) if !container.is_empty() && !container.contains(']') => {
+                (size, Some(container))
+            }
+            _ => (query, None),
+        };
+
+        let width = match arbitrary_value(size) {
+            Some(width) => width,
+            None => variants.containers().get(size)?,
+        };
+
+        Some((width, container))
+    }
+}
+
+impl VariantPlugin for ContainerQuery {
+    fn variant(&self, name: &str, variants: &Variants) -> Option<Variant> {
+        let params = match self.width_and_name(name, variants)? {
+            (width, Some(container)) => format!("{} (min-width: {})", container, width),
+            (width, None) => format!("(min-width: {})", width),
+        };
+
+        Some(Variant::at_rule("container", params))
+    }
+
+    fn order(&self, name: &str, variants: &Variants) -> usize {
+        self.width_and_name(name, variants)
+            .map_or(0, |(width, _)| width_order(width))
+    }
+}
+
+/// The value inside the square brackets of an arbitrary variant, like `900px` in `min-[900px]`
+fn arbitrary_value(value: &str) -> Option<&str> {
+    value
+        .strip_prefix('[')?
+        .strip_suffix(']')
+        .filter(|value| !value.is_empty())
+}
+
+/// Whether `value` is an `An+B` position, like `3`, `2n`, `-n + 3` or `odd`
+fn is_an_plus_b(value: &str) -> bool {
+    let value = value.trim();
+    if value == "odd" || value == "even" {
+        return true;
+    }
+
+    let (a, b) = match value.split_once('n') {
+        Some(ab) => ab,
+        None => return is_integer(value),
+    };
+
+    let a_is_valid = matches!(a, "" | "+" | "-") || is_integer(a);
+
+    // The sign of `B` can be apart from its number, like `2n + 1`
+    let b = b.trim_start();
+    let b_is_valid = b.is_empty()
+        || b.strip_prefix(['+', '-'])
+            .is_some_and(|b| is_unsigned_integer(b.trim_start()));
+
+    a_is_valid && b_is_valid
+}
+
+fn is_integer(value: &str) -> bool {
+    is_unsigned_integer(value.strip_prefix(['+', '-']).unwrap_or(value))
+}
+
+fn is_unsigned_integer(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+// Widths are sorted by their size in pixels, where a rem or an em is 16px.
+// Widths in any other unit can't be compared, so they are sorted like 0px.
+fn width_order(width: &str) -> usize {
+    let number_end = width
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(width.len());
+    let (number, unit) = width.split_at(number_end);
+
+    let px = match unit {
+        "px" => 1.0,
+        "rem" | "em" => 16.0,
+        _ => 0.0,
+    };
+
+    (number.parse::<f64>().unwrap_or(0.0) * px * 100.0) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_built_in_variants() {
+        let variants = Variants::default();
+
+        assert_eq!(variants.get("hover"), Some(Variant::selector("&:hover")));
+        assert_eq!(
+            variants.get("first"),
+            Some(Variant::selector("&:first-child"))
+        );
+        assert_eq!(
+            variants.get("file"),
+            Some(Variant::selector("&::file-selector-button"))
+        );
+        assert_eq!(
+            variants.get("md"),
+            Some(Variant::at_rule("media", "(min-width: 768px)"))
+        );
+        assert_eq!(
+            variants.get("group-hover"),
+            Some(Variant::selector(".group:hover &"))
+        );
+        assert_eq!(
+            variants.get("peer-odd"),
+            Some(Variant::selector(".peer:nth-child(odd) ~ &"))
+        );
+        assert_eq!(
+            variants.get("rtl"),
+            Some(Variant::selector("&:where([dir=\"rtl\"], [dir=\"rtl\"] *)"))
+        );
+        assert_eq!(
+            variants.get("print"),
+            Some(Variant::at_rule("media", "print"))
+        );
+        assert_eq!(
+            variants.get("group-hover/sidebar"),
+            Some(Variant::selector(".group\\/sidebar:hover &"))
+        );
+        assert_eq!(
+            variants.get("peer-checked/a.b"),
+            Some(Variant::selector(".peer\\/a\\.b:checked ~ &"))
+        );
+        assert_eq!(
+            variants.get("group-[.is-open]"),
+            Some(Variant::selector(".group.is-open &"))
+        );
+        assert_eq!(
+            variants.get("group-[.sidebar &]/nav"),
+            Some(Variant::selector(".sidebar .group\\/nav &"))
+        );
+        assert_eq!(
+            variants.get("peer-[:nth-of-type(3)]"),
+            Some(Variant::selector(".peer:nth-of-type(3) ~ &"))
+        );
+        assert_eq!(variants.get("*"), Some(Variant::selector("& > *")));
+        assert_eq!(variants.get("group-[]"), None);
+        assert_eq!(variants.get("group-before"), None);
+        assert_eq!(variants.get("group-md"), None);
+        assert_eq!(variants.get("nope"), None);
+    }
+
+    #[test]
+    fn test_variant_order() {
+        let variants = Variants::default();
+        let order = |name| variants.find(name).unwrap().1;
+
+        assert!(order("after") < order("first"));
+        assert!(order("hover") < order("disabled"));
+        assert!(order("disabled") < order("group-first"));
+        assert!(order("group-hover") < order("group-disabled"));
+        assert!(order("group-disabled") < order("group-[.is-open]"));
+        assert!(order("group-[.is-open]") < order("peer-first"));
+        assert!(order("peer-disabled") < order("dark"));
+        assert!(order("sm") < order("2xl"));
+        assert!(order("max-2xl") < order("max-[1000px]"));
+        assert!(order("max-[1000px]") < order("max-md"));
+        assert!(order("max-sm") < order("sm"));
+        assert!(order("2xl") < order("min-[20rem]"));
+        assert!(order("min-[20rem]") < order("min-[900px]"));
+    }
+
+    #[test]
+    fn test_width_variants() {
+        let variants = Variants::default();
+
+        assert_eq!(
+            variants.get("max-md"),
+            Some(Variant::at_rule("media", "not all and (min-width: 768px)"))
+        );
+        assert_eq!(
+            variants.get("max-[600px]"),
+            Some(Variant::at_rule("media", "not all and (min-width: 600px)"))
+        );
+        assert_eq!(
+            variants.get("min-[900px]"),
+            Some(Variant::at_rule("media", "(min-width: 900px)"))
+        );
+        assert_eq!(variants.get("min-md"), None);
+        assert_eq!(variants.get("max-[]"), None);
+    }
+
+    #[test]
+    fn test_added_variants() {
+        let mut variants = Variants::default();
+        variants.add("hocus", Variant::selector("&:is(:hover, :focus)"));
+        variants.add("md", Variant::at_rule("media", "(min-width: 800px)"));
+
+        assert_eq!(
+            variants.get("group-hocus"),
+            Some(Variant::selector(".group:is(:hover, :focus) &"))
+        );
+        assert_eq!(
+            variants.get("md"),
+            Some(Variant::at_rule("media", "(min-width: 800px)"))
+        );
+        assert!(variants.find("md").unwrap().1 < variants.find("lg").unwrap().1);
+        assert!(variants.find("2xl").unwrap().1 < variants.find("hocus").unwrap().1);
+    }
+
+    #[test]
+    fn test_set_screens() {
+        let mut variants = Variants::default();
+        variants.set_screens(Screens::from([
+            ("tablet".to_string(), Screen::Min("700px".into())),
+            (
+                "desktop".to_string(),
+                Screen::Range {
+                    min: "1100px".into(),
+                    max: "1599px".into(),
+                },
+            ),
+            (
+                "tall".to_string(),
+                Screen::Raw("(min-height: 800px)".into()),
+            ),
+        ]));
+
+        assert_eq!(variants.get("md"), None);
+        assert_eq!(
+            variants.get("desktop"),
+            Some(Variant::at_rule(
+                "media",
+                "(min-width: 1100px) and (max-width: 1599px)"
+            ))
+        );
+
+        let order = |name| variants.find(name).unwrap().1;
+        assert!(order("print") < order("tablet"));
+        assert!(order("tablet") < order("desktop"));
+        assert!(order("desktop") < order("tall"));
+        assert!(order("tall") < order("portrait"));
+    }
+
+    #[test]
+    fn test_set_dark_mode() {
+        let mut variants = Variants::default();
+        let order = variants.find("dark").unwrap().1;
+
+        variants.set_dark_mode(&DarkMode::Class);
+        assert_eq!(variants.get("dark"), Some(Variant::selector(".dark &")));
+        assert_eq!(variants.find("dark").unwrap().1, order);
+
+        variants.set_dark_mode(&DarkMode::Selector("[data-theme=\"dark\"]".into()));
+        assert_eq!(
+            variants.get("dark"),
+            Some(Variant::selector("[data-theme=\"dark\"] &"))
+        );
+    }
+
+    #[test]
+    fn test_container_query_variants() {
+        let mut variants = Variants::default();
+        variants
+            .containers_mut()
+            .insert("card".into(), "18rem".into());
+
+        assert_eq!(
+            variants.get("@md"),
+            Some(Variant::at_rule("container", "(min-width: 28rem)"))
+        );
+        assert_eq!(
+            variants.get("@[400px]"),
+            Some(Variant::at_rule("container", "(min-width: 400px)"))
+        );
+        assert_eq!(
+            variants.get("@card/sidebar"),
+            Some(Variant::at_rule("container", "sidebar (min-width: 18rem)"))
+        );
+        assert_eq!(variants.get("@huge"), None);
+        assert_eq!(variants.get("@md/"), None);
+
+        let order = |name| variants.find(name).unwrap().1;
+        assert!(order("landscape") < order("@xs"));
+        assert!(order("@card") < order("@xs"));
+        assert!(order("@sm/sidebar") < order("@[400px]"));
+        assert!(order("@[400px]") < order("@md"));
+    }
+
+    #[test]
+    fn test_attribute_variants() {
+        let mut variants = Variants::default();
+        variants
+            .data_mut()
+            .insert("open".into(), "state=open".into());
+        variants
+            .supports_mut()
+            .insert("grid".into(), "display: grid".into());
+
+        assert_eq!(
+            variants.get("aria-expanded"),
+            Some(Variant::selector("&[aria-expanded=\"true\"]"))
+        );
+        assert_eq!(
+            variants.get("aria-[sort=ascending]"),
+            Some(Variant::selector("&[aria-sort=ascending]"))
+        );
+        assert_eq!(
+            variants.get("data-open"),
+            Some(Variant::selector("&[data-state=open]"))
+        );
+        assert_eq!(
+            variants.get("data-[state=closed]"),
+            Some(Variant::selector("&[data-state=closed]"))
+        );
+        assert_eq!(
+            variants.get("supports-grid"),
+            Some(Variant::at_rule("supports", "(display: grid)"))
+        );
+        assert_eq!(
+            variants.get("supports-[display:grid]"),
+            Some(Variant::at_rule("supports", "(display:grid)"))
+        );
+        assert_eq!(
+            variants.get("supports-[backdrop-filter]"),
+            Some(Variant::at_rule("supports", "(backdrop-filter: var(--tw))"))
+        );
+        assert_eq!(
+            variants.get("group-aria-expanded"),
+            Some(Variant::selector(".group[aria-expanded=\"true\"] &"))
+        );
+        assert_eq!(
+            variants.get("peer-data-[state=open]/menu"),
+            Some(Variant::selector(".peer\\/menu[data-state=open] ~ &"))
+        );
+        assert_eq!(variants.get("aria-nope"), None);
+        assert_eq!(variants.get("data-closed"), None);
+        assert_eq!(variants.get("supports-flex"), None);
+        assert_eq!(variants.get("group-supports-grid"), None);
+    }
+
+    #[test]
+    fn test_relational_variants() {
+        let variants = Variants::default();
+
+        assert_eq!(
+            variants.get("has-[img]"),
+            Some(Variant::selector("&:has(img)"))
+        );
+        assert_eq!(
+            variants.get("has-checked"),
+            Some(Variant::selector("&:has(*:checked)"))
+        );
+        assert_eq!(
+            variants.get("group-has-[a:focus]"),
+            Some(Variant::selector(".group:has(a:focus) &"))
+        );
+        assert_eq!(
+            variants.get("not-first"),
+            Some(Variant::selector("&:not(:first-child)"))
+        );
+        assert_eq!(
+            variants.get("not-[.active]"),
+            Some(Variant::selector("&:not(.active)"))
+        );
+        assert_eq!(
+            variants.get("not-aria-checked"),
+            Some(Variant::selector("&:not([aria-checked=\"true\"])"))
+        );
+        assert_eq!(
+            variants.get("in-focus"),
+            Some(Variant::selector(":where(*:focus) &"))
+        );
+        assert_eq!(
+            variants.get("in-[.sidebar]"),
+            Some(Variant::selector(":where(.sidebar) &"))
+        );
+        assert_eq!(
+            variants.get("in-range"),
+            Some(Variant::selector("&:in-range"))
+        );
+        assert_eq!(variants.get("has-before"), None);
+        assert_eq!(variants.get("not-md"), None);
+        assert_eq!(variants.get("in-group-hover"), None);
+
+        let order = |name| variants.find(name).unwrap().1;
+        assert!(order("*") < order("before"));
+        assert!(order("has-hover") < order("has-disabled"));
+        assert!(order("has-disabled") < order("has-[img]"));
+        assert!(order("has-[img]") < order("not-first"));
+    }
+
+    #[test]
+    fn test_nth_variants() {
+        let variants = Variants::default();
+
+        assert_eq!(
+            variants.get("nth-3"),
+            Some(Variant::selector("&:nth-child(3)"))
+        );
+        assert_eq!(
+            variants.get("nth-[3n+1]"),
+            Some(Variant::selector("&:nth-child(3n+1)"))
+        );
+        assert_eq!(
+            variants.get("nth-last-2"),
+            Some(Variant::selector("&:nth-last-child(2)"))
+        );
+        assert_eq!(
+            variants.get("nth-of-type-[2n]"),
+            Some(Variant::selector("&:nth-of-type(2n)"))
+        );
+        assert_eq!(
+            variants.get("nth-last-of-type-[-n + 3]"),
+            Some(Variant::selector("&:nth-last-of-type(-n + 3)"))
+        );
+        assert_eq!(
+            variants.get("group-nth-[odd]"),
+            Some(Variant::selector(".group:nth-child(odd) &"))
+        );
+        assert_eq!(
+            variants.get("peer-nth-last-of-type-1"),
+            Some(Variant::selector(".peer:nth-last-of-type(1) ~ &"))
+        );
+        assert_eq!(variants.get("nth-[3x+1]"), None);
+        assert_eq!(variants.get("nth-[]"), None);
+        assert_eq!(variants.get("nth--2"), None);
+        assert_eq!(variants.get("nth-last-[n-]"), None);
+
+        let expected = "an An+B expression, like '3', '2n+1' or 'odd'".to_string();
+        assert_eq!(
+            variants.warning("nth-[2n+]"),
+            Some(WarningType::InvalidStateArg(
+                "nth-[2n+]".into(),
+                "2n+".into(),
+                expected.clone()
+            ))
+        );
+        assert_eq!(
+            variants.warning("group-nth-last-of-type-foo"),
+            Some(WarningType::InvalidStateArg(
+                "nth-last-of-type-foo".into(),
+                "foo".into(),
+                expected
+            ))
+        );
+        assert_eq!(variants.warning("nope"), None);
+
+        let order = |name| variants.find(name).unwrap().1;
+        assert!(order("nth-3") < order("nth-last-3"));
+        assert!(order("nth-last-3") < order("nth-of-type-3"));
+        assert!(order("nth-of-type-3") < order("nth-last-of-type-3"));
+    }
+
+    #[test]
+    fn test_an_plus_b() {
+        for valid in [
+            "3", "-2", "odd", "even", "n", "-n+3", "+2n", "2n + 1", "10n- 5",
+        ] {
+            assert!(is_an_plus_b(valid), "{}", valid);
+        }
+
+        for invalid in [
+            "", "3x", "n-", "2n 1", "2 n", "1 0", "2n+-1", "--n", "odd+1",
+        ] {
+            assert!(!is_an_plus_b(invalid), "{}", invalid);
+        }
+    }
+
+    struct Htmx;
+
+    impl VariantPlugin for Htmx {
+        fn variant(&self, name: &str, _: &Variants) -> Option<Variant> {
+            let state = name.strip_prefix("htmx-")?;
+            Some(Variant::selector(format!("&.htmx-{}", state)))
+        }
+    }
+
+    #[test]
+    fn test_variant_plugin() {
+        let mut variants = Variants::default();
+        variants.add_plugin(Htmx);
+
+        assert_eq!(
+            variants.get("htmx-request"),
+            Some(Variant::selector("&.htmx-request"))
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::Variant;
+
+/// The screens of the responsive variants, like `md`, in the order their media queries are emitted in
+pub type Screens = IndexMap<String, Screen>;
+
+/// A breakpoint of the responsive variants, like `md:`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Screen {
+    /// `(min-width: 768px)`
+    Min(String),
+    /// `(max-width: 767px)`
+    Max(String),
+    /// `(min-width: 768px) and (max-width: 1023px)`
+    Range { min: String, max: String },
+    /// A media query that is used as it is, like `(min-height: 800px)`
+    Raw(String),
+}
+
+impl Screen {
+    pub fn media_query(&self) -> String {
+        match self {
+            Self::Min(min) => format!("(min-width: {})", min),
+            Self::Max(max) => format!("(max-width: {})", max),
+            Self::Range { min, max } => format!("(min-width: {}) and (max-width: {})", min, max),
+            Self::Raw(raw) => raw.clone(),
+        }
+    }
+
+    /// The width the screen starts at, which is what the `container` is limited to
+    pub fn min_width(&self) -> Option<&str> {
+        match self {
+            Self::Min(min) | Self::Range { min, .. } => Some(min),
+            Self::Max(_) | Self::Raw(_) => None,
+        }
+    }
+
+    pub fn variant(&self) -> Variant {
+        Variant::at_rule("media", self.media_query())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_query() {
+        assert_eq!(
+            Screen::Min("640px".into()).media_query(),
+            "(min-width: 640px)"
+        );
+        assert_eq!(
+            Screen::Max("767px".into()).media_query(),
+            "(max-width: 767px)"
+        );
+        assert_eq!(
+            Screen::Range {
+                min: "768px".into(),
+                max: "1023px".into()
+            }
+            .media_query(),
+            "(min-width: 768px) and (max-width: 1023px)"
+        );
+        assert_eq!(
+            Screen::Raw("(min-height: 800px)".into()).media_query(),
+            "(min-height: 800px)"
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use serde::{Deserialize, Serialize};
+
+use crate::css::{AtRule, AtRuleKind, Node};
+
+/// What a variant does to the rule of a class. It either changes the selector, where `&`
+/// is the selector of the class, like `&:hover` or `.group:hover &`, or wraps the rule in
+/// an at-rule, like `@media (prefers-color-scheme: dark)`. An at-rule can also change the
+/// selector, like the arbitrary `[@media(any-hover:hover){&:hover}]`.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum Variant {
+    Selector(String),
+    AtRule {
+        name: String,
+        params: String,
+        #[serde(default)]
+        selector: Option<String>,
+    },
+}
+
+impl Variant {
+    pub fn selector(selector: impl Into<String>) -> Self {
+        Self::Selector(selector.into())
+    }
+
+    pub fn at_rule(name: impl Into<String>, params: impl Into<String>) -> Self {
+        Self::AtRule {
+            name: name.into(),
+            params: params.into(),
+            selector: None,
+        }
+    }
+
+    /// A one-off variant written in square brackets, either a selector with an `&`,
+    /// like `[&:nth-child(3)]`, or an at-rule, like `[@supports(display:grid)]`
+    pub fn arbitrary(value: &str) -> Option<Self> {
+        let variant = value.strip_prefix('[')?.strip_suffix(']')?.trim();
+
+        match variant.strip_prefix('@') {
+            Some(at_rule) => {
+                let name_end = at_rule
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+                    .unwrap_or(at_rule.len());
+                let (name, rest) = at_rule.split_at(name_end);
+
+                let (params, selector) = match rest.split_once('{') {
+                    Some((params, selector)) => {
+                        let selector = selector.strip_suffix('}')?.trim();
+                        if !selector.contains('&') {
+                            return None;
+                        }
+                        (params, Some(selector.to_string()))
+                    }
+                    None => (rest, None),
+                };
+
+                if name.is_empty() {
+                    return None;
+                }
+
+                Some(Self::AtRule {
+                    name: name.to_string(),
+                    params: params.trim().to_string(),
+                    selector,
+                })
+            }
+            None if variant.contains('&') => Some(Self::Selector(variant.to_string())),
+            None => None,
+        }
+    }
+
+    /// The selector of the variant, if it changes the selector
+    pub fn selector_template(&self) -> Option<&str> {
+        match self {
+            Self::Selector(template)
+            | Self::AtRule {
+                selector: Some(template),
+                ..
+            } => Some(template),
+            Self::AtRule { selector: None, .. } => None,
+        }
+    }
+
+    /// The pseudo class of a variant like `&:hover`, without the colon
+    pub fn pseudo_class(&self) -> Option<&str> {
+        match self {
+            Self::Selector(template) => template
+                .strip_prefix("&:")
+                .filter(|pc| !pc.starts_with(':') && !pc.contains('&')),
+            Self::AtRule { .. } => None,
+        }
+    }
+
+    /// What a variant like `&:hover` or `&[open]` adds to the selector of the element,
+    /// like `:hover` or `[open]`, so it can be added to another selector
+    pub fn compound_selector(&self) -> Option<&str> {
+        match self {
+            Self::Selector(template) => template.strip_prefix('&').filter(|compound| {
+                (compound.starts_with('[') || compound.starts_with(':'))
+                    && !compound.starts_with("::")
+                    && !compound.contains('&')
+            }),
+            Self::AtRule { .. } => None,
+        }
+    }
+
+    /// The pseudo element of a variant like `&::before`, with the colons
+    pub fn pseudo_element(&self) -> Option<&str> {
+        match self {
+            Self::Selector(template) => template
+                .strip_prefix('&')
+                .filter(|pe| pe.starts_with("::") && !pe.contains('&')),
+            Self::AtRule { .. } => None,
+        }
+    }
+
+    /// Puts `selector` wherever the variant has an `&`
+    pub fn apply_selector(&self, selector: &str) -> String {
+        match self.selector_template() {
+            Some(template) => template.replace('&', selector),
+            None => selector.to_string(),
+        }
+    }
+
+    /// The media condition of a variant that only depends on the width, like `(min-width: 768px)`
+    /// for `md` or `(not (min-width: 1280px))` for `max-xl`, so it can be combined with another one
+    pub fn breakpoint_condition(&self) -> Option<String> {
+        let params = match self {
+            Self::AtRule {
+                name,
+                params,
+                selector: None,
+            } if name == "media" => params,
+            _ => return None,
+        };
+
+        match params.strip_prefix("not all and ") {
+            Some(feature) if is_width_feature(feature) => Some(format!("(not {})", feature)),
+            Some(_) => None,
+            None if params.split(" and ").all(is_width_feature) => Some(params.clone()),
+            None => None,
+        }
+    }
+
+    /// Wraps the nodes in the at-rule of the variant, if it has one
+    pub fn wrap(&self, nodes: Vec<Node>) -> Vec<Node> {
+        match self {
+            Self::Selector(_) => nodes,
+            Self::AtRule { name, params, .. } => {
+                let kind = match name.as_str() {
+                    "media" => AtRuleKind::Media,
+                    "supports" => AtRuleKind::Supports,
+                    _ => AtRuleKind::Other(name.clone()),
+                };
+
+                vec![AtRule::new(kind, params.clone(), nodes).into()]
+            }
+        }
+    }
+}
+
+fn is_width_feature(feature: &str) -> bool {
+    (feature.starts_with("(min-width:") || feature.starts_with("(max-width:"))
+        && feature.ends_with(')')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary_variant() {
+        assert_eq!(
+            Variant::arbitrary("[&:nth-child(3)]"),
+            Some(Variant::selector("&:nth-child(3)"))
+        );
+        assert_eq!(
+            Variant::arbitrary("[.dark &]"),
+            Some(Variant::selector(".dark &"))
+        );
+        assert_eq!(
+            Variant::arbitrary("[@supports(display:grid)]"),
+            Some(Variant::at_rule("supports", "(display:grid)"))
+        );
+        assert_eq!(
+            Variant::arbitrary("[@media(any-hover:hover){&:hover}]"),
+            Some(Variant::AtRule {
+                name: "media".into(),
+                params: "(any-hover:hover)".into(),
+                selector: Some("&:hover".into())
+            })
+        );
+        assert_eq!(Variant::arbitrary("[:hover]"), None);
+        assert_eq!(Variant::arbitrary("[@]"), None);
+        assert_eq!(Variant::arbitrary("hover"), None);
+    }
+
+    #[test]
+    fn test_apply_selector() {
+        let variant = Variant::arbitrary("[&:nth-child(3)]").unwrap();
+        assert_eq!(variant.apply_selector(".x"), ".x:nth-child(3)");
+
+        let variant = Variant::arbitrary("[.dark &]").unwrap();
+        assert_eq!(variant.apply_selector(".x"), ".dark .x");
+
+        let variant = Variant::at_rule("media", "print");
+        assert_eq!(variant.apply_selector(".x"), ".x");
+    }
+
+    #[test]
+    fn test_wrap() {
+        let variant = Variant::arbitrary("[@supports(display:grid)]").unwrap();
+        assert_eq!(
+            variant.wrap(vec![]),
+            vec![AtRule::new(AtRuleKind::Supports, "(display:grid)", vec![]).into()]
+        );
+
+        let variant = Variant::arbitrary("[@container(min-width:400px)]").unwrap();
+        assert_eq!(
+            variant.wrap(vec![]),
+            vec![AtRule::new(
+                AtRuleKind::Other("container".into()),
+                "(min-width:400px)",
+                vec![]
+            )
+            .into()]
+        );
+        assert_eq!(Variant::selector("&:hover").wrap(vec![]), vec![]);
+    }
+
+    #[test]
+    fn test_pseudo_class_and_element() {
+        assert_eq!(Variant::selector("&:hover").pseudo_class(), Some("hover"));
+        assert_eq!(Variant::selector("&::before").pseudo_class(), None);
+        assert_eq!(Variant::selector("&:hover, &:focus").pseudo_class(), None);
+        assert_eq!(Variant::selector(".dark &").pseudo_class(), None);
+
+        assert_eq!(
+            Variant::selector("&::before").pseudo_element(),
+            Some("::before")
+        );
+        assert_eq!(Variant::selector("&:hover").pseudo_element(), None);
+
+        assert_eq!(
+            Variant::selector("&[open]").compound_selector(),
+            Some("[open]")
+        );
+        assert_eq!(
+            Variant::selector("&:hover").compound_selector(),
+            Some(":hover")
+        );
+        assert_eq!(Variant::selector("&::before").compound_selector(), None);
+        assert_eq!(Variant::selector("&.active").compound_selector(), None);
+    }
+
+    #[test]
+    fn test_breakpoint_condition() {
+        let condition = |params| Variant::at_rule("media", params).breakpoint_condition();
+
+        assert_eq!(
+            condition("(min-width: 768px)"),
+            Some("(min-width: 768px)".into())
+        );
+        assert_eq!(
+            condition("not all and (min-width: 1280px)"),
+            Some("(not (min-width: 1280px))".into())
+        );
+        assert_eq!(
+            condition("(min-width: 768px) and (max-width: 1023px)"),
+            Some("(min-width: 768px) and (max-width: 1023px)".into())
+        );
+        assert_eq!(condition("(prefers-color-scheme: dark)"), None);
+        assert_eq!(condition("not all and (hover: hover)"), None);
+        assert_eq!(
+            Variant::at_rule("supports", "(min-width: 1px)").breakpoint_condition(),
+            None
+        );
+    }
+}
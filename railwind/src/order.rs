@@ -0,0 +1,467 @@
+use std::cmp::Ordering;
+
+use crate::class::{Borders, Class, Layout, Spacing};
+use crate::css::Node;
+use crate::ParsedClass;
+
+/// The order of the properties, following the order of Tailwind's core plugins.
+/// A shorthand comes before its longhands, so `px-2` can override `p-4`.
+const PROPERTY_ORDER: &[&str] = &[
+    "pointer-events",
+    "visibility",
+    "position",
+    "inset",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "isolation",
+    "z-index",
+    "order",
+    "grid-column",
+    "grid-column-start",
+    "grid-column-end",
+    "grid-row",
+    "grid-row-start",
+    "grid-row-end",
+    "float",
+    "clear",
+    "margin",
+    "margin-top",
+    "margin-right",
+    "margin-bottom",
+    "margin-left",
+    "box-sizing",
+    "display",
+    "aspect-ratio",
+    "height",
+    "max-height",
+    "min-height",
+    "width",
+    "max-width",
+    "min-width",
+    "flex",
+    "flex-shrink",
+    "flex-grow",
+    "flex-basis",
+    "table-layout",
+    "border-collapse",
+    "--tw-border-spacing-x",
+    "--tw-border-spacing-y",
+    "border-spacing",
+    "transform-origin",
+    "--tw-translate-x",
+    "--tw-translate-y",
+    "--tw-rotate",
+    "--tw-skew-x",
+    "--tw-skew-y",
+    "--tw-scale-x",
+    "--tw-scale-y",
+    "transform",
+    "animation",
+    "cursor",
+    "--tw-pan-x",
+    "--tw-pan-y",
+    "--tw-pinch-zoom",
+    "touch-action",
+    "user-select",
+    "resize",
+    "scroll-snap-type",
+    "--tw-scroll-snap-strictness",
+    "scroll-snap-align",
+    "scroll-snap-stop",
+    "scroll-margin",
+    "scroll-margin-top",
+    "scroll-margin-right",
+    "scroll-margin-bottom",
+    "scroll-margin-left",
+    "scroll-padding",
+    "scroll-padding-top",
+    "scroll-padding-right",
+    "scroll-padding-bottom",
+    "scroll-padding-left",
+    "list-style-position",
+    "list-style-type",
+    "appearance",
+    "columns",
+    "break-before",
+    "break-inside",
+    "break-after",
+    "grid-auto-columns",
+    "grid-template-columns",
+    "grid-auto-flow",
+    "grid-auto-rows",
+    "grid-template-rows",
+    "flex-direction",
+    "flex-wrap",
+    "place-content",
+    "place-items",
+    "align-content",
+    "align-items",
+    "justify-content",
+    "justify-items",
+    "gap",
+    "column-gap",
+    "row-gap",
+    "--tw-space-x-reverse",
+    "--tw-space-y-reverse",
+    "--tw-divide-x-reverse",
+    "--tw-divide-y-reverse",
+    "place-self",
+    "align-self",
+    "justify-self",
+    "overflow",
+    "overflow-x",
+    "overflow-y",
+    "overscroll-behavior",
+    "overscroll-behavior-x",
+    "overscroll-behavior-y",
+    "scroll-behavior",
+    "text-overflow",
+    "white-space",
+    "overflow-wrap",
+    "word-break",
+    "border-radius",
+    "border-top-left-radius",
+    "border-top-right-radius",
+    "border-bottom-right-radius",
+    "border-bottom-left-radius",
+    "border-width",
+    "border-top-width",
+    "border-right-width",
+    "border-bottom-width",
+    "border-left-width",
+    "border-style",
+    "border-color",
+    "border-top-color",
+    "border-right-color",
+    "border-bottom-color",
+    "border-left-color",
+    "background-color",
+    "background-image",
+    "--tw-gradient-from",
+    "--tw-gradient-stops",
+    "--tw-gradient-to",
+    "box-decoration-break",
+    "background-size",
+    "background-attachment",
+    "background-clip",
+    "background-position",
+    "background-repeat",
+    "background-origin",
+    "fill",
+    "stroke",
+    "stroke-width",
+    "object-fit",
+    "object-position",
+    "padding",
+    "padding-top",
+    "padding-right",
+    "padding-bottom",
+    "padding-left",
+    "text-align",
+    "text-indent",
+    "vertical-align",
+    "font-family",
+    "font-size",
+    "font-weight",
+    "text-transform",
+    "font-style",
+    "--tw-ordinal",
+    "--tw-slashed-zero",
+    "--tw-numeric-figure",
+    "--tw-numeric-spacing",
+    "--tw-numeric-fraction",
+    "font-variant-numeric",
+    "line-height",
+    "letter-spacing",
+    "color",
+    "text-decoration-line",
+    "text-decoration-color",
+    "text-decoration-style",
+    "text-decoration-thickness",
+    "text-underline-offset",
+    "font-smoothing",
+    "caret-color",
+    "accent-color",
+    "opacity",
+    "background-blend-mode",
+    "mix-blend-mode",
+    "box-shadow",
+    "--tw-shadow-color",
+    "outline-style",
+    "outline-width",
+    "outline-offset",
+    "outline-color",
+    "--tw-ring-inset",
+    "--tw-ring-color",
+    "--tw-ring-offset-width",
+    "--tw-ring-offset-color",
+    "--tw-blur",
+    "--tw-brightness",
+    "--tw-contrast",
+    "--tw-drop-shadow",
+    "--tw-grayscale",
+    "--tw-hue-rotate",
+    "--tw-invert",
+    "--tw-saturate",
+    "--tw-sepia",
+    "filter",
+    "--tw-backdrop-blur",
+    "--tw-backdrop-brightness",
+    "--tw-backdrop-contrast",
+    "--tw-backdrop-grayscale",
+    "--tw-backdrop-hue-rotate",
+    "--tw-backdrop-invert",
+    "--tw-backdrop-opacity",
+    "--tw-backdrop-saturate",
+    "--tw-backdrop-sepia",
+    "backdrop-filter",
+    "transition-property",
+    "transition-delay",
+    "transition-duration",
+    "transition-timing-function",
+    "will-change",
+    "--tw-content",
+    "content",
+    // Container queries come from a plugin in Tailwind, so they go after the core utilities
+    "container-type",
+    "container-name",
+];
+
+/// The base styles, like the preflight, are always printed first
+/// and aren't sorted, so only components and utilities have a layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Layer {
+    Components,
+    Utilities,
+}
+
+/// The position of a class in the output. Classes are sorted by their layer, then by their
+/// states, so rules with states come after the plain ones, then by their properties.
+/// The class name is only compared when everything else is equal, so the output
+/// never depends on the order of the classes in the source.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SortKey {
+    layer: Layer,
+    states: Vec<(usize, usize)>,
+    // Arbitrary properties come after every other utility with the same states
+    arbitrary_property: bool,
+    properties: Properties,
+    class: ClassName,
+    // Utilities that style the children sort by their own variable
+    // instead of the margins and borders of the children
+    variables_only: bool,
+}
+
+impl SortKey {
+    /// Everything but the properties, which are only known once the class is turned into nodes
+    pub fn new(parsed: &ParsedClass) -> Self {
+        let layer = match parsed.class {
+            Class::Layout(Layout::Container(_)) => Layer::Components,
+            _ => Layer::Utilities,
+        };
+
+        // Comparing the orders from the highest down works like Tailwind's bitmask of
+        // variants, so `md:hover:` comes after both `hover:` and `md:`
+        let mut states: Vec<(usize, usize)> = parsed.states.iter().map(|s| s.order()).collect();
+        states.sort_unstable_by(|a, b| b.cmp(a));
+
+        Self {
+            layer,
+            states,
+            arbitrary_property: matches!(parsed.class, Class::ArbitraryProperty(_)),
+            properties: Properties(vec![]),
+            class: ClassName(parsed.raw_class_name.to_string()),
+            variables_only: matches!(
+                parsed.class,
+                Class::Spacing(Spacing::SpaceBetween(_)) | Class::Borders(Borders::DivideWidth(_))
+            ),
+        }
+    }
+
+    pub fn with_properties(mut self, nodes: &[Node]) -> Self {
+        self.properties = Properties::new(nodes, self.variables_only);
+        self
+    }
+}
+
+/// The sorted positions of the properties of a class in `PROPERTY_ORDER`
+#[derive(Debug, PartialEq, Eq)]
+struct Properties(Vec<usize>);
+
+impl Properties {
+    fn new(nodes: &[Node], variables_only: bool) -> Self {
+        let mut order: Vec<usize> = first_rule_properties(nodes)
+            .into_iter()
+            .filter(|p| !variables_only || p.starts_with("--"))
+            .filter_map(property_order)
+            .collect();
+        order.sort_unstable();
+
+        Self(order)
+    }
+}
+
+impl PartialOrd for Properties {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Properties {
+    // Classes without any known property go last and when two classes start with the
+    // same properties, the one with more of them goes first, so `px-4` comes before `pl-2`
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_empty(), other.0.is_empty()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            _ => (),
+        }
+
+        for (a, b) in self.0.iter().zip(&other.0) {
+            if a != b {
+                return a.cmp(b);
+            }
+        }
+
+        other.0.len().cmp(&self.0.len())
+    }
+}
+
+/// Compares the numbers in class names by their value, so `p-2` comes before `p-10`
+#[derive(Debug, PartialEq, Eq)]
+struct ClassName(String);
+
+impl PartialOrd for ClassName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ClassName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut a = self.0.chars().peekable();
+        let mut b = other.0.chars().peekable();
+
+        loop {
+            match (a.peek(), b.peek()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                    let x = take_number(&mut a);
+                    let y = take_number(&mut b);
+                    let ordering = x.len().cmp(&y.len()).then(x.cmp(&y));
+
+                    if ordering != Ordering::Equal {
+                        return ordering;
+                    }
+                }
+                (Some(x), Some(y)) => {
+                    let ordering = x.cmp(y);
+
+                    if ordering != Ordering::Equal {
+                        return ordering;
+                    }
+
+                    a.next();
+                    b.next();
+                }
+            }
+        }
+    }
+}
+
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut number = String::new();
+
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+        number.push(c);
+    }
+
+    // Leading zeros don't change the value
+    let trimmed = number.trim_start_matches('0');
+    if trimmed.is_empty() {
+        "0".into()
+    } else {
+        trimmed.into()
+    }
+}
+
+fn first_rule_properties(nodes: &[Node]) -> Vec<&str> {
+    for node in nodes {
+        match node {
+            Node::Rule(rule) => {
+                return rule
+                    .declarations
+                    .iter()
+                    .map(|d| d.property.as_str())
+                    .collect()
+            }
+            Node::AtRule(at_rule) => {
+                let properties = first_rule_properties(&at_rule.nodes);
+                if !properties.is_empty() {
+                    return properties;
+                }
+            }
+        }
+    }
+
+    vec![]
+}
+
+fn property_order(property: &str) -> Option<usize> {
+    let unprefixed = ["-webkit-", "-moz-", "-moz-osx-"]
+        .iter()
+        .rev()
+        .find_map(|prefix| property.strip_prefix(prefix))
+        .unwrap_or(property);
+
+    PROPERTY_ORDER.iter().position(|p| *p == unprefixed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_properties_order() {
+        let p = Properties(vec![property_order("padding").unwrap()]);
+        let px = Properties(vec![
+            property_order("padding-right").unwrap(),
+            property_order("padding-left").unwrap(),
+        ]);
+        let pl = Properties(vec![property_order("padding-left").unwrap()]);
+        let pr = Properties(vec![property_order("padding-right").unwrap()]);
+
+        assert!(p < px);
+        assert!(px < pl);
+        assert!(px < pr);
+        assert!(pl < Properties(vec![]));
+    }
+
+    #[test]
+    fn test_class_name_order() {
+        let name = |n: &str| ClassName(n.to_string());
+
+        assert!(name("p-0.5") < name("p-1"));
+        assert!(name("p-1.5") < name("p-10"));
+        assert!(name("p-2") < name("p-10"));
+        assert!(name("text-amber-50") < name("text-amber-100"));
+        assert!(name("w-1/2") < name("w-1/3"));
+    }
+
+    #[test]
+    fn test_vendor_prefixes() {
+        assert_eq!(
+            property_order("-webkit-user-select"),
+            property_order("user-select")
+        );
+        assert_eq!(
+            property_order("-moz-osx-font-smoothing"),
+            property_order("-webkit-font-smoothing")
+        );
+    }
+}
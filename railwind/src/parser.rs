@@ -1,30 +1,62 @@
-use indexmap::IndexSet;
-use lazy_static::lazy_static;
 use nom::branch::alt;
-use nom::bytes::complete::{is_not, tag, take_until};
-use nom::character::complete::{multispace1, space1};
-use nom::combinator::{map, map_opt};
-use nom::error::ParseError;
-use nom::multi::separated_list0;
+use nom::bytes::complete::{is_not, tag};
+use nom::character::complete::{anychar, multispace0, multispace1};
+use nom::combinator::recognize;
+use nom::multi::{many0, many1, many_till, separated_list0};
 use nom::sequence::{delimited, preceded, terminated};
 use nom::IResult;
-use std::collections::HashMap;
 
-use crate::class::Class;
+/// Collects every class inside every `class="..."` or `className="..."` attribute
+pub fn class_attrs(input: &str) -> Vec<&str> {
+    many0(preceded(many_till(anychar, class_attr_start), class_attr))(input)
+        .map(|(_, attrs)| attrs.into_iter().flatten().collect())
+        .unwrap_or_default()
+}
+
+fn class_attr_start(input: &str) -> IResult<&str, &str> {
+    alt((tag("class=\""), tag("className=\"")))(input)
+}
 
-fn class_attr(input: &str) -> IResult<&str, IndexSet<Class>> {
-    map(
-        delimited(
-            preceded(take_until("class=\""), tag("class=\"")),
-            classes,
-            tag("\""),
-        ),
-        |classes| classes.into_iter().filter_map(|c| Class::new(c)).collect(),
-    )(input)
+pub fn class_attr(input: &str) -> IResult<&str, Vec<&str>> {
+    terminated(delimited(multispace0, classes, multispace0), tag("\""))(input)
 }
 
 fn classes(input: &str) -> IResult<&str, Vec<&str>> {
-    separated_list0(multispace1, is_not("\"\n\t "))(input)
+    separated_list0(multispace1, is_not("\"\n\t\r "))(input)
+}
+
+/// Splits the states off of a raw class, leaving the class value as the remaining input.
+/// Colons inside arbitrary values are not treated as state separators.
+pub fn states(input: &str) -> IResult<&str, Vec<&str>> {
+    many0(terminated(state, tag(":")))(input)
+}
+
+fn state(input: &str) -> IResult<&str, &str> {
+    recognize(many1(alt((brackets, is_not("[:")))))(input)
+}
+
+// Square brackets with everything inside them, including other brackets like in `[&[open]]`
+fn brackets(input: &str) -> IResult<&str, &str> {
+    let mut depth = 0;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok((&input[i + 1..], &input[..i + 1]));
+                }
+            }
+            _ if depth == 0 => break,
+            _ => (),
+        }
+    }
+
+    Err(nom::Err::Error(nom::error::Error::new(
+        input,
+        nom::error::ErrorKind::Char,
+    )))
 }
 
 #[cfg(test)]
@@ -35,4 +67,34 @@ mod tests {
     fn test_classes() {
         assert_eq!(classes("p-5 m-5"), Ok(("", vec!["p-5", "m-5"])));
     }
+
+    #[test]
+    fn test_class_attrs() {
+        assert_eq!(
+            class_attrs(r#"<div class=" p-5 m-5 "><p className="text-xl">"#),
+            vec!["p-5", "m-5", "text-xl"]
+        );
+    }
+
+    #[test]
+    fn test_states() {
+        assert_eq!(states("p-5"), Ok(("p-5", vec![])));
+        assert_eq!(states("hover:md:p-5"), Ok(("p-5", vec!["hover", "md"])));
+        assert_eq!(
+            states("bg-[length:200px_100px]"),
+            Ok(("bg-[length:200px_100px]", vec![]))
+        );
+        assert_eq!(
+            states("before:content-['a:b']"),
+            Ok(("content-['a:b']", vec!["before"]))
+        );
+        assert_eq!(
+            states("[&:nth-child(3)]:underline"),
+            Ok(("underline", vec!["[&:nth-child(3)]"]))
+        );
+        assert_eq!(
+            states("[&[open]]:hover:underline"),
+            Ok(("underline", vec!["[&[open]]", "hover"]))
+        );
+    }
 }
@@ -1,129 +1,101 @@
-use crate::warning::WarningType;
-
-// Indents every line in a string with two spaces
-// and removes empty lines
-pub fn indent_string(str: &str) -> String {
-    str.lines().map(|line| format!("    {}\n", line)).collect()
-}
-
 pub fn replace_invalid_chars(selector: impl Into<String>) -> String {
-    let invalid_chars = ['[', ']', '%', ':', '.', '/', '(', ')', '\'', '#'];
-    let mut val: String = selector.into();
-
-    if val.contains(invalid_chars) {
-        let mut to_escape = vec![];
-        for (index, c) in val.chars().enumerate() {
-            if invalid_chars.contains(&c) {
-                to_escape.push(index);
+    let invalid_chars = [
+        '[', ']', '%', ':', '.', '/', '(', ')', '\'', '"', '#', '+', '*', '=', '!', '@', '$', '^',
+        '&', '>', '<', '~', '{', '}', '|', '?', ';', '`',
+    ];
+    let selector: String = selector.into();
+    let mut val = String::with_capacity(selector.len());
+
+    for (i, c) in selector.chars().enumerate() {
+        match c {
+            // A class can't start with a digit, like `2xl:p-1`, unless it's escaped as a code point
+            c if i == 0 && c.is_ascii_digit() => val.push_str(&format!("\\{:x} ", c as u32)),
+            ',' => val.push_str("\\2c "),
+            c if invalid_chars.contains(&c) => {
+                val.push('\\');
+                val.push(c);
             }
-        }
-
-        to_escape.reverse();
-
-        for index in to_escape {
-            val.insert(index, '\\');
+            c => val.push(c),
         }
     }
 
-    val.replace(",", "\\2c ")
+    val
 }
 
-pub fn get_class_name<'a>(value: &'a str) -> &'a str {
-    if value.starts_with('-') {
-        if let Some(index) = value[1..].find('-') {
-            return &value[..index + 1];
-        }
-        {
-            value
+// Rewrites the values inside square brackets the way Tailwind reads them:
+// underscores become spaces, single quotes become double quotes and
+// a leading dot gets a zero in front of it
+pub fn normalize_arbitrary_values(class: &str) -> String {
+    let mut out = String::with_capacity(class.len() + 1);
+    let mut depth = 0;
+    let mut chars = class.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth > 0 => depth -= 1,
+            // Only numbers, a selector like `[.dark_&]` stays the way it is
+            '.' if depth > 0
+                && out.ends_with('[')
+                && chars.peek().is_some_and(|c| c.is_ascii_digit()) =>
+            {
+                out.push('0')
+            }
+            _ => (),
         }
-    } else if let Some(index) = value.find('-') {
-        &value[..index]
-    } else {
-        value
-    }
-}
 
-pub fn get_args<'a>(value: &'a str) -> Result<&'a str, WarningType> {
-    if value.starts_with('-') {
-        if let Some(index) = value[1..].find('-') {
-            return Ok(&value[index + 2..]);
+        match c {
+            '_' if depth > 0 => out.push(' '),
+            '\'' if depth > 0 => out.push('"'),
+            _ => out.push(c),
         }
-    } else if let Some(index) = value.find('-') {
-        return Ok(&value[index + 1..]);
     }
 
-    Err(WarningType::InvalidArgCount(value.to_string()))
-}
-
-pub fn get_opt_args<'a>(value: &'a str) -> &'a str {
-    if value.starts_with('-') {
-        if let Some(index) = value[1..].find('-') {
-            &value[index + 2..]
-        } else {
-            ""
-        }
-    } else if let Some(index) = value.find('-') {
-        &value[index + 1..]
-    } else {
-        ""
-    }
+    out
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_indent_string() {
-        assert_eq!(indent_string("test"), "    test\n");
-        assert_eq!(
-            indent_string(
-                r#"test
-    123
-"#
-            ),
-            r#"    test
-        123
-"#
-        );
-    }
-
     #[test]
     fn test_replace_invalid_chars() {
         assert_eq!(replace_invalid_chars("space-x-5"), "space-x-5");
         assert_eq!(replace_invalid_chars("space-x-[25%]"), r"space-x-\[25\%\]");
-    }
-
-    #[test]
-    fn test_get_class_name() {
-        assert_eq!(get_class_name("aspect-auto"), "aspect");
-        assert_eq!(get_class_name("flex"), "flex");
-        assert_eq!(get_class_name("-mx-5"), "-mx");
-        assert_eq!(get_class_name("-m"), "-m");
-    }
-
-    #[test]
-    fn test_get_args() {
-        assert_eq!(get_args("aspect-auto"), Ok("auto"));
         assert_eq!(
-            get_args("flex"),
-            Err(WarningType::InvalidArgCount("flex".into()))
+            replace_invalid_chars("[--x:calc(100%+1px)]"),
+            r"\[--x\:calc\(100\%\+1px\)\]"
+        );
+        assert_eq!(
+            replace_invalid_chars("before:content-['→']"),
+            r"before\:content-\[\'→\'\]"
+        );
+        assert_eq!(replace_invalid_chars("2xl:p-1"), r"\32 xl\:p-1");
+        assert_eq!(replace_invalid_chars("md:2xl:p-1"), r"md\:2xl\:p-1");
+        assert_eq!(
+            replace_invalid_chars("content-['é:x']"),
+            r"content-\[\'é\:x\'\]"
         );
-        assert_eq!(get_args("space-x-5"), Ok("x-5"));
-        assert_eq!(get_args("-space-x-5"), Ok("x-5"));
-        assert_eq!(get_args("-mx-5"), Ok("5"));
-        let args = get_args("space-x-5").unwrap();
-        let params = get_args(args).unwrap();
-
-        assert_eq!(params, "5");
     }
 
     #[test]
-    fn test_get_opt_args() {
-        assert_eq!(get_opt_args("aspect-auto"), "auto");
-        assert_eq!(get_opt_args("flex"), "");
-        assert_eq!(get_opt_args("space-x-5"), "x-5");
-        assert_eq!(get_opt_args("-space-x-5"), "x-5");
-        assert_eq!(get_opt_args("-mx-5"), "5");
+    fn test_normalize_arbitrary_values() {
+        assert_eq!(normalize_arbitrary_values("px-5"), "px-5");
+        assert_eq!(
+            normalize_arbitrary_values("bg-[center_top_1rem]"),
+            "bg-[center top 1rem]"
+        );
+        assert_eq!(
+            normalize_arbitrary_values("before:content-['Hello_World']"),
+            "before:content-[\"Hello World\"]"
+        );
+        assert_eq!(
+            normalize_arbitrary_values("tracking-[.25em]"),
+            "tracking-[0.25em]"
+        );
+        assert_eq!(
+            normalize_arbitrary_values("[.dark_&]:flex"),
+            "[.dark &]:flex"
+        );
     }
 }
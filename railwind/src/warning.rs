@@ -3,11 +3,15 @@ use core::fmt;
 #[derive(Debug, PartialEq, Hash)]
 pub enum WarningType {
     StateNotFound(String),
+    /// A state with an argument it can't use, with the state, the argument and what it expects
+    InvalidStateArg(String, String, String),
     ClassNotFound,
     InvalidArg(String, String, Vec<&'static str>),
     ValueNotFound(String),
     InvalidArgCount(String),
     InvalidArbitraryArg(String),
+    /// A source file that couldn't be read, with the reason
+    FileNotRead(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -23,6 +27,10 @@ impl Warning {
                 "Could not match state at class '{}', '{}' is not a valid state",
                 class, recieved
             ),
+            WarningType::InvalidStateArg(state, arg, expected) => format!(
+                "Could not match state at class '{}', invalid argument '{}' of state '{}', expected {}",
+                class, arg, state, expected
+            ),
             WarningType::ClassNotFound => format!("Could not match class '{}'", class),
             WarningType::InvalidArg(recieved, tried_as, required) => {
                 format!(
@@ -42,6 +50,9 @@ impl Warning {
             WarningType::InvalidArbitraryArg(value) => {
                 format!("Could not extract arbitrary value from class '{class}', invalid argument '{value}'")
             }
+            WarningType::FileNotRead(reason) => {
+                format!("Could not read file '{}', {}", position.file, reason)
+            }
         };
 
         Self {
@@ -75,6 +86,18 @@ impl Position {
             column,
         }
     }
+
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
 }
 
 impl From<(usize, usize)> for Position {
@@ -1,12 +1,23 @@
 use std::collections::HashMap;
 
-use railwind::CollectionOptions;
+use indexmap::IndexMap;
+use railwind::config::Theme;
+use railwind::{CollectionOptions, DarkMode, Variant};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Config {
     pub content: Vec<String>,
     pub extend_collection_options: Option<HashMap<String, CollectionOptions>>,
+    /// Variants added after the built in ones, in the order they are written
+    #[serde(default)]
+    pub variants: Option<IndexMap<String, Variant>>,
+    /// Theme tables that replace the built in ones, or extend them with `extend`
+    #[serde(default)]
+    pub theme: Option<Theme>,
+    /// How `dark:` decides the dark theme is on, `Media` if it's not set
+    #[serde(default)]
+    pub dark_mode: Option<DarkMode>,
 }
 
 impl Default for Config {
@@ -14,6 +25,9 @@ impl Default for Config {
         Self {
             content: vec!["index.html".to_string()],
             extend_collection_options: None,
+            variants: None,
+            theme: None,
+            dark_mode: None,
         }
     }
 }
@@ -21,6 +35,7 @@ impl Default for Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use railwind::Screen;
 
     #[test]
     fn test_deserialize() {
@@ -49,4 +64,91 @@ mod tests {
             CollectionOptions::Html
         ));
     }
+
+    #[test]
+    fn test_deserialize_theme() {
+        let config = r##"
+            (
+                content: [],
+                extend_collection_options: None,
+                theme: Some((
+                    spacing: {
+                        "padding": { "gutter": "18px" },
+                    },
+                    screens: Some({
+                        "tablet": Min("700px"),
+                        "laptop": Range(min: "1024px", max: "1279px"),
+                        "tall": Raw("(min-height: 800px)"),
+                    }),
+                    extend: (
+                        backgrounds: {
+                            "color": { "brand": "#bada55" },
+                        },
+                    ),
+                )),
+            )"##;
+
+        let theme = ron::from_str::<Config>(config).unwrap().theme.unwrap();
+        assert_eq!(theme.spacing["padding"]["gutter"], "18px");
+        assert_eq!(theme.extend.backgrounds["color"]["brand"], "#bada55");
+        assert!(theme.borders.is_empty());
+
+        let screens: Vec<_> = theme.screens.unwrap().into_iter().collect();
+        assert_eq!(
+            screens,
+            vec![
+                ("tablet".to_string(), Screen::Min("700px".into())),
+                (
+                    "laptop".to_string(),
+                    Screen::Range {
+                        min: "1024px".into(),
+                        max: "1279px".into()
+                    }
+                ),
+                (
+                    "tall".to_string(),
+                    Screen::Raw("(min-height: 800px)".into())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_dark_mode() {
+        let config = r#"
+            (
+                content: [],
+                extend_collection_options: None,
+                dark_mode: Some(Selector("[data-theme='dark']")),
+            )"#;
+
+        let dark_mode = ron::from_str::<Config>(config).unwrap().dark_mode;
+        assert_eq!(
+            dark_mode,
+            Some(DarkMode::Selector("[data-theme='dark']".into()))
+        );
+    }
+
+    #[test]
+    fn test_deserialize_variants() {
+        let config = r#"
+            (
+                content: [],
+                extend_collection_options: None,
+                variants: Some({
+                    "hocus": Selector("&:is(:hover, :focus)"),
+                    "supports-grid": AtRule(name: "supports", params: "(display: grid)"),
+                })
+            )"#;
+
+        let variants = ron::from_str::<Config>(config).unwrap().variants.unwrap();
+        assert_eq!(
+            variants.get("hocus"),
+            Some(&Variant::selector("&:is(:hover, :focus)"))
+        );
+        assert_eq!(
+            variants.get("supports-grid"),
+            Some(&Variant::at_rule("supports", "(display: grid)"))
+        );
+    }
 }
@@ -1,8 +1,9 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use config::Config;
 use notify::event::ModifyKind;
 use notify::{Error, Event, EventKind, RecursiveMode, Watcher};
-use railwind::{parse_to_string, CollectionOptions, Source, SourceOptions};
+use railwind::css::{url_comment, PrintMode, Printer};
+use railwind::{CollectionOptions, Compiler, SourceOptions};
 use ron::ser::PrettyConfig;
 use std::fs::{self, File};
 use std::io::Write;
@@ -33,6 +34,66 @@ struct Args {
     /// Generate a default config file at the current directory
     #[arg(short = 'g', long, default_value = "false")]
     generate: bool,
+
+    /// How the generated CSS is formatted
+    #[arg(short = 'f', long, value_enum, default_value = "pretty")]
+    format: Format,
+
+    /// Number of spaces nested rules are indented with
+    #[arg(long, default_value = "4")]
+    indent: usize,
+
+    /// Generate a source map, either next to the output file or inlined into it
+    #[arg(short = 's', long, value_enum)]
+    source_map: Option<SourceMapOutput>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Format {
+    Pretty,
+    Compact,
+    Minified,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum SourceMapOutput {
+    File,
+    Inline,
+}
+
+impl Args {
+    fn compiler(&self, config: &Config) -> Compiler {
+        let mode = match self.format {
+            Format::Pretty => PrintMode::Pretty,
+            Format::Compact => PrintMode::Compact,
+            Format::Minified => PrintMode::Minified,
+        };
+
+        let mut compiler = Compiler::default()
+            .include_preflight(self.include_preflight)
+            .printer(Printer::new(mode).indent(self.indent));
+
+        if let Some(dark_mode) = &config.dark_mode {
+            compiler.config_mut().variants.set_dark_mode(dark_mode);
+        }
+
+        for (name, variant) in config.variants.iter().flatten() {
+            compiler
+                .config_mut()
+                .variants
+                .add(name.clone(), variant.clone());
+        }
+
+        if let Some(theme) = &config.theme {
+            if let Err(unknown) = compiler.config_mut().apply_theme(theme) {
+                for table in unknown {
+                    println!("{table}");
+                }
+            }
+        }
+
+        compiler
+    }
 }
 
 fn main() {
@@ -50,43 +111,60 @@ fn main() {
     let input: Vec<PathBuf> = get_paths_from_config(&config);
     let output = Path::new(&args.output);
 
+    let mut compiler = args.compiler(&config);
+
+    run_parsing(&compiler, &input, output, &config, args.source_map);
+
     if args.watch {
-        let mut watcher = notify::recommended_watcher(|res: Result<Event, Error>| match res {
-            Ok(event) => match event.kind {
-                EventKind::Modify(m) => match m {
-                    ModifyKind::Data(_) => {
-                        println!("Running parser");
-                        let start = Instant::now();
-
-                        let args = Args::parse();
-                        let config = parse_config(&args.config);
-                        let input: Vec<PathBuf> = get_paths_from_config(&config);
-                        let output = Path::new(&args.output);
-
-                        run_parsing(&args, input, output, &config);
-
-                        let duration = start.elapsed();
-                        println!("Parsing took: {:?}", duration);
+        let output = output.to_path_buf();
+        let mut watched = input.clone();
+        watched.push(PathBuf::from(&args.config));
+        let mut config = config;
+
+        let mut watcher = notify::recommended_watcher(move |res: Result<Event, Error>| match res {
+            Ok(event) => {
+                if let EventKind::Modify(ModifyKind::Data(_)) = event.kind {
+                    println!("Running parser");
+                    let start = Instant::now();
+
+                    // Only a change to the config rebuilds the compiler. Its content paths are
+                    // still the ones from startup, since those are the files being watched
+                    if event
+                        .paths
+                        .iter()
+                        .any(|p| same_file(p, Path::new(&args.config)))
+                    {
+                        config = parse_config(&args.config);
+                        compiler = args.compiler(&config);
                     }
-                    _ => (),
-                },
-                _ => (),
-            },
+
+                    run_parsing(&compiler, &input, &output, &config, args.source_map);
+
+                    let duration = start.elapsed();
+                    println!("Parsing took: {:?}", duration);
+                }
+            }
             Err(e) => panic!("{}", e),
         })
         .unwrap();
 
-        for watch_path in &input {
+        for watch_path in &watched {
             watcher
-                .watch(&watch_path, RecursiveMode::NonRecursive)
+                .watch(watch_path, RecursiveMode::NonRecursive)
                 .unwrap();
         }
 
-        run_parsing(&args, input, output, &config);
+        // The watcher runs on its own thread, so this one only has to stay alive
+        loop {
+            std::thread::park();
+        }
+    }
+}
 
-        loop {}
-    } else {
-        run_parsing(&args, input, output, &config);
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
     }
 }
 
@@ -100,6 +178,9 @@ fn parse_config(config_path: &str) -> Config {
             Config {
                 content: vec!["index.html".to_string()],
                 extend_collection_options: None,
+                variants: None,
+                theme: None,
+                dark_mode: None,
             }
         }
     }
@@ -128,13 +209,19 @@ fn get_paths_from_config(config: &Config) -> Vec<PathBuf> {
     out_paths
 }
 
-fn run_parsing(args: &Args, input: Vec<PathBuf>, output: &Path, config: &Config) {
+fn run_parsing(
+    compiler: &Compiler,
+    input: &[PathBuf],
+    output: &Path,
+    config: &Config,
+    source_map: Option<SourceMapOutput>,
+) {
     let mut warnings = vec![];
 
     let source_options: Vec<SourceOptions> = input
         .iter()
         .map(|i| SourceOptions {
-            input: &i,
+            input: i,
             option: if let Some(extension) = i.extension() {
                 if let Some(str) = extension.to_str() {
                     CollectionOptions::new(str, config.extend_collection_options.clone())
@@ -147,11 +234,26 @@ fn run_parsing(args: &Args, input: Vec<PathBuf>, output: &Path, config: &Config)
         })
         .collect();
 
-    let css = parse_to_string(
-        Source::Files(source_options),
-        args.include_preflight,
-        &mut warnings,
-    );
+    let stylesheet = compiler.stylesheet_from_files(&source_options, &mut warnings);
+    let (mut css, map) = compiler.finish_with_source_map(&stylesheet);
+
+    let file_name = output
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let map = map
+        .file(&file_name)
+        .sources_relative_to(output.parent().unwrap_or(Path::new("")));
+
+    match source_map {
+        Some(SourceMapOutput::File) => {
+            let map_name = format!("{}.map", file_name);
+            fs::write(output.with_file_name(&map_name), map.to_json()).unwrap();
+            css.push_str(&url_comment(&map_name));
+        }
+        Some(SourceMapOutput::Inline) => css.push_str(&map.to_inline_comment()),
+        None => (),
+    }
 
     let mut css_file = File::create(output).unwrap();
     css_file.write_all(css.as_bytes()).unwrap();